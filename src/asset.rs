@@ -1,30 +1,51 @@
 use std::convert::TryFrom;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 use cosmwasm_std::{
-    to_binary, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, StdError, StdResult, Uint128, WasmMsg,
+    to_binary, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Decimal, Fraction, OverflowError,
+    OverflowOperation, QuerierWrapper, Uint128, Uint256, WasmMsg,
 };
-use cw20::Cw20ExecuteMsg;
+use cw20::{Cw20ExecuteMsg, Expiration};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::asset_info::{AssetInfo, AssetInfoBase, AssetInfoUnchecked};
+use super::error::AssetError;
+
+/// The denominator of `Decimal`'s fixed-point representation, i.e. 10^18
+const DECIMAL_FRACTIONAL: u128 = 1_000_000_000_000_000_000;
 
 /// Represents a fungible asset with a known amount
 ///
 /// Each asset instance contains two values: [`info`], which specifies the asset's type (CW20 or
 /// native), and its [`amount`], which specifies the asset's amount
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct AssetBase<T> {
+///
+/// Equality of assets is exact: two assets are equal only if both their infos and amounts are
+/// equal. For comparisons tolerating small differences in amounts, see [`Asset::approx_eq`].
+///
+/// The amount type defaults to [`Uint128`], which is what [`Asset`] and [`AssetUnchecked`] use and
+/// what all helper methods operate on. Other amount types, e.g. signed integers for representing
+/// balance deltas, can be plugged in, but only get the constructors and trait implementations.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AssetBase<T, A = Uint128> {
     /// Specifies the asset's type (CW20 or native)
     pub info: AssetInfoBase<T>,
     /// Specifies the asset's amount
-    pub amount: Uint128,
+    pub amount: A,
+}
+
+// `Uint128` does not implement `Hash`, so hash the raw amount instead
+impl<T: Hash> Hash for AssetBase<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.info.hash(state);
+        self.amount.u128().hash(state);
+    }
 }
 
-impl<T> AssetBase<T> {
+impl<T, A> AssetBase<T, A> {
     /// Create a new **asset** instance based on given asset info and amount
     ///
     /// To create an unchecked instance, the [`info`] parameter may be either checked or unchecked;
@@ -40,7 +61,7 @@ impl<T> AssetBase<T> {
     /// let info2 = AssetInfo::native("uusd");
     /// let asset2 = Asset::new(info2, 67890u128);
     /// ```
-    pub fn new<A: Into<AssetInfoBase<T>>, B: Into<Uint128>>(info: A, amount: B) -> Self {
+    pub fn new<I: Into<AssetInfoBase<T>>, B: Into<A>>(info: I, amount: B) -> Self {
         Self {
             info: info.into(),
             amount: amount.into(),
@@ -59,7 +80,7 @@ impl<T> AssetBase<T> {
     ///
     /// let asset = Asset::cw20(Addr::unchecked("token_addr"), 12345u128);
     /// ```
-    pub fn cw20<A: Into<T>, B: Into<Uint128>>(contract_addr: A, amount: B) -> Self {
+    pub fn cw20<C: Into<T>, B: Into<A>>(contract_addr: C, amount: B) -> Self {
         Self {
             info: AssetInfoBase::cw20(contract_addr),
             amount: amount.into(),
@@ -73,7 +94,7 @@ impl<T> AssetBase<T> {
     ///
     /// let asset = Asset::native("uusd", 12345u128);
     /// ```
-    pub fn native<A: Into<String>, B: Into<Uint128>>(denom: A, amount: B) -> Self {
+    pub fn native<D: Into<String>, B: Into<A>>(denom: D, amount: B) -> Self {
         Self {
             info: AssetInfoBase::native(denom),
             amount: amount.into(),
@@ -87,22 +108,20 @@ pub type AssetUnchecked = AssetBase<String>;
 pub type Asset = AssetBase<Addr>;
 
 impl FromStr for AssetUnchecked {
-    type Err = StdError;
+    type Err = AssetError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let words: Vec<&str> = s.split(":").collect();
         if words.len() != 3 {
-            return Err(StdError::generic_err(
-                format!("invalid asset format `{}`; must be in format `native:{{denom}}:{{amount}}` or `cw20:{{contract_addr}}:{{amount}}`", s)
-            ));
+            return Err(AssetError::InvalidAssetFormat {
+                received: s.to_string(),
+            });
         }
 
         let info = AssetInfoUnchecked::from_str(&format!("{}:{}", words[0], words[1]))?;
-        let amount = Uint128::from_str(words[2]).map_err(
-            |_| StdError::generic_err(
-                format!("invalid asset amount `{}`; must be a 128-bit unsigned integer", words[2])
-            )
-        )?;
+        let amount = Uint128::from_str(words[2]).map_err(|_| AssetError::InvalidAssetAmount {
+            amount: words[2].to_string(),
+        })?;
 
         Ok(AssetUnchecked { info, amount })
     }
@@ -135,15 +154,42 @@ impl AssetUnchecked {
     ///     }
     /// }
     /// ```
-    pub fn check(&self, api: &dyn Api, optional_whitelist: Option<&[&str]>) -> StdResult<Asset> {
+    pub fn check(
+        &self,
+        api: &dyn Api,
+        optional_whitelist: Option<&[&str]>,
+    ) -> Result<Asset, AssetError> {
         Ok(Asset {
             info: self.info.check(api, optional_whitelist)?,
             amount: self.amount,
         })
     }
+
+    /// Similar to `check`, but additionally assert that the amount is non-zero
+    ///
+    /// Useful for endpoints where a zero deposit is considered an error. The plain `check` method
+    /// accepts zero amounts.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Api, StdResult};
+    /// use cw_asset::{Asset, AssetUnchecked};
+    ///
+    /// fn validate_deposit(api: &dyn Api, asset_unchecked: &AssetUnchecked) -> StdResult<Asset> {
+    ///     Ok(asset_unchecked.check_nonzero(api)?)
+    /// }
+    /// ```
+    pub fn check_nonzero(&self, api: &dyn Api) -> Result<Asset, AssetError> {
+        let asset = self.check(api, None)?;
+        if asset.amount.is_zero() {
+            return Err(AssetError::ZeroAmount {
+                info: asset.info.to_string(),
+            });
+        }
+        Ok(asset)
+    }
 }
 
-impl fmt::Display for Asset {
+impl<T: fmt::Display, A: fmt::Display> fmt::Display for AssetBase<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}:{}", self.info, self.amount)
     }
@@ -165,22 +211,22 @@ impl From<&Coin> for Asset {
 }
 
 impl TryFrom<Asset> for Coin {
-    type Error = StdError;
+    type Error = AssetError;
     fn try_from(asset: Asset) -> Result<Self, Self::Error> {
         match &asset.info {
             AssetInfo::Native(denom) => Ok(Coin {
                 denom: denom.clone(),
                 amount: asset.amount,
             }),
-            AssetInfo::Cw20(_) => Err(StdError::generic_err(
-                format!("cannot cast asset {} into cosmwasm_std::Coin", asset.to_string())
-            )),
+            AssetInfo::Cw20(_) => Err(AssetError::CannotCastToCoin {
+                asset: asset.to_string(),
+            }),
         }
     }
 }
 
 impl TryFrom<&Asset> for Coin {
-    type Error = StdError;
+    type Error = AssetError;
     fn try_from(asset: &Asset) -> Result<Self, Self::Error> {
         Coin::try_from(asset.clone())
     }
@@ -203,7 +249,412 @@ impl std::cmp::PartialEq<Coin> for Asset {
     }
 }
 
+/// Common interface of fungible assets that can be transferred and queried
+///
+/// The built-in CW20 and native variants implement this trait via [`Asset`]. Downstream crates
+/// supporting other kinds of assets, e.g. CW1155 semi-fungible tokens, may implement it for their
+/// own types. The transfer methods of [`AssetList`](crate::AssetList) dispatch through this trait
+/// via [`transfer_all`](crate::transfer_all), which can be called with such types directly:
+///
+/// ```rust
+/// use cosmwasm_std::{Addr, StdResult};
+/// use cw_asset::{transfer_all, Asset, AssetLike};
+///
+/// # fn main() -> StdResult<()> {
+/// let uusd = Asset::native("uusd", 12345u128);
+/// let token = Asset::cw20(Addr::unchecked("token_addr"), 67890u128);
+/// let assets: Vec<&dyn AssetLike> = vec![&uusd, &token];
+/// let msgs = transfer_all(&assets, "alice")?;  // one bank message, then one cw20 transfer
+/// # Ok(())
+/// # }
+/// ```
+pub trait AssetLike {
+    /// Generate a message that transfers the asset from the sender to account `to`
+    fn transfer_msg(&self, to: &str) -> Result<CosmosMsg, AssetError>;
+
+    /// Query an address' balance of the asset
+    fn query_balance(&self, querier: &QuerierWrapper, address: &str) -> Result<Uint128, AssetError>;
+
+    /// Return the asset as a native coin, if it is one
+    ///
+    /// [`transfer_all`](crate::transfer_all) sends native coins together in a single bank message
+    /// instead of calling `transfer_msg` on each. Defaults to `None`.
+    fn native_coin(&self) -> Option<Coin> {
+        None
+    }
+}
+
+impl<L: AssetLike + ?Sized> AssetLike for &L {
+    fn transfer_msg(&self, to: &str) -> Result<CosmosMsg, AssetError> {
+        (**self).transfer_msg(to)
+    }
+
+    fn query_balance(
+        &self,
+        querier: &QuerierWrapper,
+        address: &str,
+    ) -> Result<Uint128, AssetError> {
+        (**self).query_balance(querier, address)
+    }
+
+    fn native_coin(&self) -> Option<Coin> {
+        (**self).native_coin()
+    }
+}
+
+impl AssetLike for Asset {
+    fn transfer_msg(&self, to: &str) -> Result<CosmosMsg, AssetError> {
+        Asset::transfer_msg(self, to)
+    }
+
+    fn query_balance(
+        &self,
+        querier: &QuerierWrapper,
+        address: &str,
+    ) -> Result<Uint128, AssetError> {
+        self.info.query_balance(querier, address)
+    }
+
+    fn native_coin(&self) -> Option<Coin> {
+        Coin::try_from(self).ok()
+    }
+}
+
+/// Specifies the direction in which to round the result of a multiplication that is not exact
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round down towards zero
+    Floor,
+    /// Round up away from zero
+    Ceil,
+}
+
+/// Add two assets of the same kind
+///
+/// NOTE: Panics if the two assets are not of the same kind, or if the addition overflows. In
+/// contracts, prefer [`Asset::checked_add`] instead.
+impl std::ops::Add for Asset {
+    type Output = Asset;
+
+    fn add(self, other: Asset) -> Asset {
+        &self + &other
+    }
+}
+
+impl<'a> std::ops::Add<&'a Asset> for &'a Asset {
+    type Output = Asset;
+
+    fn add(self, other: &'a Asset) -> Asset {
+        self.checked_add(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Subtract an asset from another of the same kind
+///
+/// NOTE: Panics if the two assets are not of the same kind, or if the subtraction underflows. In
+/// contracts, prefer [`Asset::checked_sub`] instead.
+impl std::ops::Sub for Asset {
+    type Output = Asset;
+
+    fn sub(self, other: Asset) -> Asset {
+        &self - &other
+    }
+}
+
+impl<'a> std::ops::Sub<&'a Asset> for &'a Asset {
+    type Output = Asset;
+
+    fn sub(self, other: &'a Asset) -> Asset {
+        self.checked_sub(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
 impl Asset {
+    /// Return a new **asset** instance of the same kind, with the given amount
+    ///
+    /// ```rust
+    /// use cw_asset::Asset;
+    ///
+    /// let asset = Asset::native("uusd", 12345u128);
+    /// let doubled = asset.with_amount(asset.amount.u128() * 2);  // should be `native:uusd:24690`
+    /// ```
+    pub fn with_amount<A: Into<Uint128>>(&self, amount: A) -> Asset {
+        Asset::new(self.info.clone(), amount)
+    }
+
+    /// Return whether two assets are of the same kind, and their amounts differ by no more than
+    /// `tolerance`
+    ///
+    /// NOTE: This is _not_ an equivalence relation, and is not used by `==`, which always compares
+    /// amounts exactly. Do not rely on it where consistency with `Eq` is required, e.g. for keys of
+    /// hash sets or maps.
+    ///
+    /// ```rust
+    /// use cw_asset::Asset;
+    ///
+    /// let a = Asset::native("uusd", 12345u128);
+    /// let b = Asset::native("uusd", 12346u128);
+    ///
+    /// let approx_equal = a.approx_eq(&b, 1u128);  // should be true, while `a == b` is false
+    /// ```
+    pub fn approx_eq<A: Into<Uint128>>(&self, other: &Asset, tolerance: A) -> bool {
+        let diff = if self.amount > other.amount {
+            self.amount - other.amount
+        } else {
+            other.amount - self.amount
+        };
+        self.info == other.info && diff <= tolerance.into()
+    }
+
+    /// Add another asset of the same kind, returning a new **asset** instance
+    ///
+    /// Throws an error if the two assets are not of the same kind, or if the addition overflows.
+    ///
+    /// ```rust
+    /// use cw_asset::Asset;
+    ///
+    /// let a = Asset::native("uusd", 12345u128);
+    /// let b = Asset::native("uusd", 67890u128);
+    ///
+    /// let sum = a.checked_add(&b).unwrap();  // should be 80235 uusd
+    /// ```
+    pub fn checked_add(&self, other: &Asset) -> Result<Asset, AssetError> {
+        if self.info != other.info {
+            return Err(AssetError::IncompatibleOperands {
+                operation: String::from("add"),
+                left: self.info.to_string(),
+                right: other.info.to_string(),
+            });
+        }
+        Ok(Asset::new(self.info.clone(), self.amount.checked_add(other.amount)?))
+    }
+
+    /// Subtract another asset of the same kind, returning a new **asset** instance
+    ///
+    /// Throws an error if the two assets are not of the same kind, or if the subtraction
+    /// underflows.
+    ///
+    /// ```rust
+    /// use cw_asset::Asset;
+    ///
+    /// let a = Asset::native("uusd", 67890u128);
+    /// let b = Asset::native("uusd", 12345u128);
+    ///
+    /// let difference = a.checked_sub(&b).unwrap();  // should be 55545 uusd
+    /// ```
+    pub fn checked_sub(&self, other: &Asset) -> Result<Asset, AssetError> {
+        if self.info != other.info {
+            return Err(AssetError::IncompatibleOperands {
+                operation: String::from("subtract"),
+                left: self.info.to_string(),
+                right: other.info.to_string(),
+            });
+        }
+        Ok(Asset::new(self.info.clone(), self.amount.checked_sub(other.amount)?))
+    }
+
+    /// Multiply the asset's amount by a ratio, i.e. `amount * numerator / denominator`, rounded
+    /// down, returning a new **asset** instance of the same kind
+    ///
+    /// The intermediate product is computed as a `Uint256`, so this does not overflow unless the
+    /// final result does. Throws an error if the denominator is zero, or if the result does not fit in a
+    /// `Uint128`.
+    ///
+    /// ```rust
+    /// use cw_asset::Asset;
+    ///
+    /// let pool = Asset::native("uusd", 12345u128);
+    /// let share = pool.checked_mul_ratio(1u128, 3u128).unwrap();  // should be 4115 uusd
+    /// ```
+    pub fn checked_mul_ratio<A: Into<Uint128>, B: Into<Uint128>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Result<Asset, AssetError> {
+        let numerator: Uint128 = numerator.into();
+        let denominator: Uint128 = denominator.into();
+        if denominator.is_zero() {
+            return Err(AssetError::ZeroDenominator {
+                asset: self.to_string(),
+            });
+        }
+
+        let amount = self.amount.full_mul(numerator) / Uint256::from(denominator);
+        let amount = Uint128::try_from(amount)
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul, self.amount, numerator))?;
+        Ok(Asset::new(self.info.clone(), amount))
+    }
+
+    /// Multiply the asset's amount by a decimal factor, rounded down, returning a new **asset**
+    /// instance of the same kind
+    ///
+    /// Throws an error if the result does not fit in a `Uint128`.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Decimal;
+    /// use cw_asset::Asset;
+    ///
+    /// let amount = Asset::native("uusd", 12345u128);
+    /// let fee = amount.checked_mul(Decimal::permille(3)).unwrap();  // should be 37 uusd
+    /// ```
+    pub fn checked_mul(&self, factor: Decimal) -> Result<Asset, AssetError> {
+        self.checked_mul_ratio(factor.numerator(), factor.denominator())
+    }
+
+    /// Multiply the asset's amount by a decimal factor, rounded in the specified direction,
+    /// returning a new **asset** instance of the same kind
+    ///
+    /// Throws an error if the result does not fit in a `Uint128`.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Decimal;
+    /// use cw_asset::{Asset, RoundingMode};
+    ///
+    /// let amount = Asset::native("uusd", 12345u128);
+    /// let rate = Decimal::permille(3);
+    /// let fee = amount.checked_mul_rounded(rate, RoundingMode::Ceil).unwrap();  // should be 38 uusd
+    /// ```
+    pub fn checked_mul_rounded(
+        &self,
+        factor: Decimal,
+        mode: RoundingMode,
+    ) -> Result<Asset, AssetError> {
+        let floor = self.checked_mul(factor)?;
+        if mode == RoundingMode::Floor {
+            return Ok(floor);
+        }
+
+        // the product is exact iff `amount * numerator` is divisible by the denominator; reducing
+        // both factors modulo the denominator first keeps the multiplication from overflowing
+        let numerator: Uint128 = factor.numerator().into();
+        let denominator: Uint128 = factor.denominator().into();
+        let remainder = Uint128::new(self.amount.u128() % denominator.u128())
+            .checked_mul(Uint128::new(numerator.u128() % denominator.u128()))?
+            .u128()
+            % denominator.u128();
+        if remainder == 0 {
+            Ok(floor)
+        } else {
+            Ok(Asset::new(floor.info, floor.amount.checked_add(Uint128::new(1))?))
+        }
+    }
+
+    /// Return the minimum acceptable amount of the asset given a slippage tolerance, i.e.
+    /// `amount * (1 - slippage)`, rounded down
+    ///
+    /// A slippage of 100% or more is clamped, resulting in an asset of zero amount.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Decimal;
+    /// use cw_asset::Asset;
+    ///
+    /// let expected = Asset::native("uusd", 12345u128);
+    /// let min = expected.min_received(Decimal::percent(1)).unwrap();  // should be 12221 uusd
+    /// ```
+    pub fn min_received(&self, slippage: Decimal) -> Result<Asset, AssetError> {
+        let amount = if slippage >= Decimal::one() {
+            Uint128::zero()
+        } else {
+            self.amount * (Decimal::one() - slippage)
+        };
+        Ok(Asset::new(self.info.clone(), amount))
+    }
+
+    /// Express the asset's amount as a percentage of a reference asset of the same kind, e.g. to
+    /// report that a deposit makes up 12.34% of a pool
+    ///
+    /// Throws an error if the two assets are not of the same kind, if the reference amount is
+    /// zero, or if the percentage is too large to be represented as a `Decimal`.
+    ///
+    /// ```rust
+    /// use cw_asset::Asset;
+    ///
+    /// let deposit = Asset::native("uusd", 1234u128);
+    /// let pool = Asset::native("uusd", 10000u128);
+    ///
+    /// let percent = deposit.percent_of(&pool).unwrap();  // should be 12.34
+    /// ```
+    pub fn percent_of(&self, reference: &Asset) -> Result<Decimal, AssetError> {
+        if self.info != reference.info {
+            return Err(AssetError::AssetMismatch {
+                asset: self.to_string(),
+                reference: reference.to_string(),
+            });
+        }
+        if reference.amount.is_zero() {
+            return Err(AssetError::ZeroReferenceAmount {
+                asset: self.to_string(),
+            });
+        }
+        // `Decimal::from_ratio` panics if the result does not fit, so compute the raw, 18-decimal
+        // value of the percentage with overflow checks first
+        let raw = self.checked_mul_ratio(100u128 * DECIMAL_FRACTIONAL, reference.amount)?.amount;
+        Ok(Decimal::from_ratio(raw, DECIMAL_FRACTIONAL))
+    }
+
+    /// Create a new **asset** instance representing the amount received, given an account's
+    /// balance of the asset before and after an operation
+    ///
+    /// Throws an error if the balance has decreased.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Deps, Env, StdResult, Uint128};
+    /// use cw_asset::{Asset, AssetInfo};
+    ///
+    /// fn measure_received(deps: Deps, env: &Env, balance_before: Uint128) -> StdResult<Asset> {
+    ///     let info = AssetInfo::native("uusd");
+    ///     let balance_after = info.query_balance(&deps.querier, &env.contract.address)?;
+    ///     Ok(Asset::from_balance_change(info, balance_before, balance_after)?)
+    /// }
+    /// ```
+    pub fn from_balance_change<A: Into<AssetInfo>>(
+        info: A,
+        before: Uint128,
+        after: Uint128,
+    ) -> Result<Self, AssetError> {
+        Ok(Asset::new(info, after.checked_sub(before)?))
+    }
+
+    /// Format the asset in a human-readable form, `{amount} {denom}` for native coins or
+    /// `{amount} {contract_addr}` for CW20 tokens
+    ///
+    /// Unlike the [`fmt::Display`] implementation, the output omits the asset type and is not meant
+    /// to be parsed back.
+    ///
+    /// ```rust
+    /// use cw_asset::Asset;
+    ///
+    /// let asset = Asset::native("uusd", 12345u128);
+    /// let s = asset.to_human_string();  // should be "12345 uusd"
+    /// ```
+    pub fn to_human_string(&self) -> String {
+        match &self.info {
+            AssetInfo::Cw20(contract_addr) => format!("{} {}", self.amount, contract_addr),
+            AssetInfo::Native(denom) => format!("{} {}", self.amount, denom),
+        }
+    }
+
+    /// Query an address' balance of the asset; a shortcut for `asset.info.query_balance`
+    ///
+    /// NOTE: The asset's own amount is not involved in the query.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+    /// use cw_asset::Asset;
+    ///
+    /// fn query_balance(deps: Deps, asset: &Asset, account_addr: &Addr) -> StdResult<Uint128> {
+    ///     Ok(asset.query_balance(&deps.querier, account_addr)?)
+    /// }
+    /// ```
+    pub fn query_balance<A: Into<String>>(
+        &self,
+        querier: &QuerierWrapper,
+        address: A,
+    ) -> Result<Uint128, AssetError> {
+        self.info.query_balance(querier, address)
+    }
+
     /// Generate a message that sends a CW20 token to the specified recipient with a binary payload
     ///
     /// NOTE: Only works for CW20 tokens. Returns error if invoked on an [`Asset`] instance
@@ -228,7 +679,7 @@ impl Asset {
     ///         .add_attribute("asset_sent", asset.to_string()))
     /// }
     /// ```
-    pub fn send_msg<A: Into<String>>(&self, to: A, msg: Binary) -> StdResult<CosmosMsg> {
+    pub fn send_msg<A: Into<String>>(&self, to: A, msg: Binary) -> Result<CosmosMsg, AssetError> {
         match &self.info {
             AssetInfo::Cw20(contract_addr) => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: contract_addr.into(),
@@ -239,9 +690,7 @@ impl Asset {
                 })?,
                 funds: vec![],
             })),
-            AssetInfo::Native(_) => {
-                Err(StdError::generic_err("native coins do not have `send` method"))
-            }
+            AssetInfo::Native(_) => Err(AssetError::not_supported_for_native("send")),
         }
     }
 
@@ -259,7 +708,7 @@ impl Asset {
     ///         .add_attribute("asset_sent", asset.to_string()))
     /// }
     /// ```
-    pub fn transfer_msg<A: Into<String>>(&self, to: A) -> StdResult<CosmosMsg> {
+    pub fn transfer_msg<A: Into<String>>(&self, to: A) -> Result<CosmosMsg, AssetError> {
         match &self.info {
             AssetInfo::Cw20(contract_addr) => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: contract_addr.into(),
@@ -279,6 +728,65 @@ impl Asset {
         }
     }
 
+    /// Generate messages that split the asset among multiple recipients, one transfer message per
+    /// recipient
+    ///
+    /// Throws an error if the recipients' amounts do not add up to exactly the asset's amount.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Response, StdResult, Uint128};
+    /// use cw_asset::Asset;
+    ///
+    /// fn distribute_fees(fees: &Asset, shares: &[(String, Uint128)]) -> StdResult<Response> {
+    ///     let msgs = fees.transfer_to_many_msg(shares)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_messages(msgs)
+    ///         .add_attribute("asset_distributed", fees.to_string()))
+    /// }
+    /// ```
+    pub fn transfer_to_many_msg(
+        &self,
+        recipients: &[(String, Uint128)],
+    ) -> Result<Vec<CosmosMsg>, AssetError> {
+        let total = recipients
+            .iter()
+            .try_fold(Uint128::zero(), |total, (_, amount)| total.checked_add(*amount))?;
+        if total != self.amount {
+            return Err(AssetError::SplitMismatch {
+                asset: self.to_string(),
+                total,
+            });
+        }
+
+        recipients
+            .iter()
+            .map(|(to, amount)| Asset::new(self.info.clone(), *amount).transfer_msg(to))
+            .collect()
+    }
+
+    /// Generate a message that refunds the asset to a specified account, e.g. when a deposit is
+    /// rejected
+    ///
+    /// Currently this is identical to `transfer_msg`, but it is named for intent, so that
+    /// refund-specific logic can be added in the future.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Response, StdResult};
+    /// use cw_asset::Asset;
+    ///
+    /// fn refund_asset(asset: &Asset, sender_addr: &Addr) -> StdResult<Response> {
+    ///     let msg = asset.refund_msg(sender_addr)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_message(msg)
+    ///         .add_attribute("asset_refunded", asset.to_string()))
+    /// }
+    /// ```
+    pub fn refund_msg<A: Into<String>>(&self, to: A) -> Result<CosmosMsg, AssetError> {
+        self.transfer_msg(to)
+    }
+
     /// Generate a message that draws the asset from the account specified by [`from`] to the one
     /// specified by [`to`]
     ///
@@ -301,7 +809,7 @@ impl Asset {
         &self,
         from: A,
         to: B,
-    ) -> StdResult<CosmosMsg> {
+    ) -> Result<CosmosMsg, AssetError> {
         match &self.info {
             AssetInfo::Cw20(contract_addr) => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: contract_addr.into(),
@@ -313,40 +821,185 @@ impl Asset {
                 funds: vec![],
             })),
             AssetInfo::Native(_) => {
-                Err(StdError::generic_err("native coins do not have `transfer_from` method"))
+                Err(AssetError::not_supported_for_native("transfer_from"))
             }
         }
     }
-}
-
-//--------------------------------------------------------------------------------------------------
-// Optional Feature: Astroport Legacy Support
-//--------------------------------------------------------------------------------------------------
 
-#[cfg(feature = "astroport")]
-impl From<Asset> for astroport::asset::Asset {
-    fn from(asset: Asset) -> Self {
-        Self {
-            info: asset.info.into(),
-            amount: asset.amount,
+    /// Generate a message that increases the allowance of `spender` to draw the asset from the
+    /// message sender's account
+    ///
+    /// NOTE: Only works for CW20 tokens. Returns error if invoked on an [`Asset`] instance
+    /// representing a native coin, as native coins do not have an equivalent method implemented.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Response, StdResult};
+    /// use cw_asset::Asset;
+    ///
+    /// fn approve_asset(asset: &Asset, spender_addr: &Addr) -> StdResult<Response> {
+    ///     let msg = asset.increase_allowance_msg(spender_addr, None)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_message(msg)
+    ///         .add_attribute("asset_approved", asset.to_string()))
+    /// }
+    /// ```
+    pub fn increase_allowance_msg<A: Into<String>>(
+        &self,
+        spender: A,
+        expires: Option<Expiration>,
+    ) -> Result<CosmosMsg, AssetError> {
+        match &self.info {
+            AssetInfo::Cw20(contract_addr) => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: contract_addr.into(),
+                msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: spender.into(),
+                    amount: self.amount,
+                    expires,
+                })?,
+                funds: vec![],
+            })),
+            AssetInfo::Native(_) => {
+                Err(AssetError::not_supported_for_native("increase_allowance"))
+            }
         }
     }
-}
-
-#[cfg(feature = "astroport")]
-impl From<&Asset> for astroport::asset::Asset {
-    fn from(asset: &Asset) -> Self {
-        asset.clone().into()
-    }
-}
 
-#[cfg(feature = "astroport")]
-impl From<astroport::asset::Asset> for Asset {
-    fn from(legacy_asset: astroport::asset::Asset) -> Self {
-        Self {
-            info: legacy_asset.info.into(),
-            amount: legacy_asset.amount,
-        }
+    /// Generate a message that decreases the allowance of `spender` to draw the asset from the
+    /// message sender's account
+    ///
+    /// NOTE: Only works for CW20 tokens. Returns error if invoked on an [`Asset`] instance
+    /// representing a native coin, as native coins do not have an equivalent method implemented.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Response, StdResult};
+    /// use cw_asset::Asset;
+    ///
+    /// fn revoke_asset(asset: &Asset, spender_addr: &Addr) -> StdResult<Response> {
+    ///     let msg = asset.decrease_allowance_msg(spender_addr, None)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_message(msg)
+    ///         .add_attribute("asset_revoked", asset.to_string()))
+    /// }
+    /// ```
+    pub fn decrease_allowance_msg<A: Into<String>>(
+        &self,
+        spender: A,
+        expires: Option<Expiration>,
+    ) -> Result<CosmosMsg, AssetError> {
+        match &self.info {
+            AssetInfo::Cw20(contract_addr) => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: contract_addr.into(),
+                msg: to_binary(&Cw20ExecuteMsg::DecreaseAllowance {
+                    spender: spender.into(),
+                    amount: self.amount,
+                    expires,
+                })?,
+                funds: vec![],
+            })),
+            AssetInfo::Native(_) => {
+                Err(AssetError::not_supported_for_native("decrease_allowance"))
+            }
+        }
+    }
+
+    /// Generate a message that burns the asset
+    ///
+    /// NOTE: Only works for CW20 tokens. Returns error if invoked on an [`Asset`] instance
+    /// representing a native coin, as native coins do not have an equivalent method implemented.
+    /// Token factory denoms can be burned with `factory_burn_msg` instead, under the
+    /// `token-factory` feature.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Response, StdResult};
+    /// use cw_asset::Asset;
+    ///
+    /// fn burn_asset(asset: &Asset) -> StdResult<Response> {
+    ///     let msg = asset.burn_msg()?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_message(msg)
+    ///         .add_attribute("asset_burned", asset.to_string()))
+    /// }
+    /// ```
+    pub fn burn_msg(&self) -> Result<CosmosMsg, AssetError> {
+        match &self.info {
+            AssetInfo::Cw20(contract_addr) => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: contract_addr.into(),
+                msg: to_binary(&Cw20ExecuteMsg::Burn {
+                    amount: self.amount,
+                })?,
+                funds: vec![],
+            })),
+            AssetInfo::Native(_) => Err(AssetError::not_supported_for_native("burn")),
+        }
+    }
+
+    /// Deduct a coin from the asset, returning a new **asset** instance with the reduced amount
+    ///
+    /// NOTE: Only works for native coins of the same denom. Returns error if invoked on an [`Asset`]
+    /// instance representing a CW20 token, if the denoms do not match, or if the coin's amount
+    /// exceeds that of the asset.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Coin, StdResult};
+    /// use cw_asset::Asset;
+    ///
+    /// fn deduct_fee(asset: &Asset, fee: &Coin) -> StdResult<Asset> {
+    ///     Ok(asset.deduct_coin(fee)?)
+    /// }
+    /// ```
+    pub fn deduct_coin(&self, coin: &Coin) -> Result<Asset, AssetError> {
+        match &self.info {
+            AssetInfo::Native(denom) => {
+                if *denom != coin.denom {
+                    return Err(AssetError::DeductDenomMismatch {
+                        coin: coin.to_string(),
+                        asset: self.to_string(),
+                    });
+                }
+                Ok(Asset {
+                    info: self.info.clone(),
+                    amount: self.amount.checked_sub(coin.amount)?,
+                })
+            }
+            AssetInfo::Cw20(_) => Err(AssetError::DeductFromCw20 {
+                coin: coin.to_string(),
+                asset: self.to_string(),
+            }),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Optional Feature: Astroport Legacy Support
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "astroport")]
+impl From<Asset> for astroport::asset::Asset {
+    fn from(asset: Asset) -> Self {
+        Self {
+            info: asset.info.into(),
+            amount: asset.amount,
+        }
+    }
+}
+
+#[cfg(feature = "astroport")]
+impl From<&Asset> for astroport::asset::Asset {
+    fn from(asset: &Asset) -> Self {
+        asset.clone().into()
+    }
+}
+
+#[cfg(feature = "astroport")]
+impl From<astroport::asset::Asset> for Asset {
+    fn from(legacy_asset: astroport::asset::Asset) -> Self {
+        Self {
+            info: legacy_asset.info.into(),
+            amount: legacy_asset.amount,
+        }
     }
 }
 
@@ -371,6 +1024,164 @@ impl std::cmp::PartialEq<astroport::asset::Asset> for Asset {
     }
 }
 
+//--------------------------------------------------------------------------------------------------
+// Optional Feature: CW20 Balance Support
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "cw20-balance")]
+impl From<Asset> for cw20::Balance {
+    fn from(asset: Asset) -> Self {
+        match asset.info {
+            AssetInfo::Cw20(address) => cw20::Balance::from(cw20::Cw20CoinVerified {
+                address,
+                amount: asset.amount,
+            }),
+            AssetInfo::Native(denom) => cw20::Balance::from(vec![Coin {
+                denom,
+                amount: asset.amount,
+            }]),
+        }
+    }
+}
+
+#[cfg(feature = "cw20-balance")]
+impl From<&Asset> for cw20::Balance {
+    fn from(asset: &Asset) -> Self {
+        asset.clone().into()
+    }
+}
+
+#[cfg(feature = "cw20-balance")]
+impl TryFrom<cw20::Balance> for Asset {
+    type Error = AssetError;
+    fn try_from(balance: cw20::Balance) -> Result<Self, Self::Error> {
+        match balance {
+            cw20::Balance::Cw20(coin) => Ok(Asset::cw20(coin.address, coin.amount)),
+            cw20::Balance::Native(native_balance) => match &native_balance.0[..] {
+                [coin] => Ok(coin.into()),
+                coins => Err(AssetError::CannotCastNativeBalance {
+                    count: coins.len(),
+                }),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "cw20-balance")]
+impl TryFrom<&cw20::Balance> for Asset {
+    type Error = AssetError;
+    fn try_from(balance: &cw20::Balance) -> Result<Self, Self::Error> {
+        Asset::try_from(balance.clone())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Optional Feature: Token Factory Support
+//--------------------------------------------------------------------------------------------------
+
+/// Mirrors `osmosis.tokenfactory.v1beta1.MsgMint`
+#[cfg(feature = "token-factory")]
+#[derive(Clone, PartialEq, prost::Message)]
+struct MsgMint {
+    #[prost(string, tag = "1")]
+    sender: String,
+    #[prost(message, optional, tag = "2")]
+    amount: Option<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+}
+
+/// Mirrors `osmosis.tokenfactory.v1beta1.MsgBurn`
+#[cfg(feature = "token-factory")]
+#[derive(Clone, PartialEq, prost::Message)]
+struct MsgBurn {
+    #[prost(string, tag = "1")]
+    sender: String,
+    #[prost(message, optional, tag = "2")]
+    amount: Option<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+}
+
+// These are prefixed with `factory_`, as `burn_msg` is already taken by the CW20 burn message,
+// which takes no sender; `factory_mint_msg` is named alike for symmetry
+#[cfg(feature = "token-factory")]
+impl Asset {
+    /// Generate a message that mints the asset via the token factory module; `sender` must be the
+    /// admin of the denom, typically the contract itself
+    ///
+    /// NOTE: Only works for native coins whose denom is of the form `factory/{creator}/{subdenom}`.
+    /// Returns error if invoked on any other native coin or on a CW20 token.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Env, Response, StdResult};
+    /// use cw_asset::Asset;
+    ///
+    /// fn mint_asset(env: &Env, asset: &Asset) -> StdResult<Response> {
+    ///     let msg = asset.factory_mint_msg(&env.contract.address)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_message(msg)
+    ///         .add_attribute("asset_minted", asset.to_string()))
+    /// }
+    /// ```
+    pub fn factory_mint_msg<A: Into<String>>(&self, sender: A) -> Result<CosmosMsg, AssetError> {
+        use prost::Message;
+
+        let msg = MsgMint {
+            sender: sender.into(),
+            amount: Some(self.to_factory_coin()?),
+        };
+        Ok(CosmosMsg::Stargate {
+            type_url: String::from("/osmosis.tokenfactory.v1beta1.MsgMint"),
+            value: msg.encode_to_vec().into(),
+        })
+    }
+
+    /// Generate a message that burns the asset via the token factory module; `sender` must be the
+    /// admin of the denom, typically the contract itself, and hold the coins to be burned
+    ///
+    /// NOTE: Only works for native coins whose denom is of the form `factory/{creator}/{subdenom}`.
+    /// Returns error if invoked on any other native coin or on a CW20 token; to burn CW20 tokens,
+    /// use [`Asset::burn_msg`] instead.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Env, Response, StdResult};
+    /// use cw_asset::Asset;
+    ///
+    /// fn burn_asset(env: &Env, asset: &Asset) -> StdResult<Response> {
+    ///     let msg = asset.factory_burn_msg(&env.contract.address)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_message(msg)
+    ///         .add_attribute("asset_burned", asset.to_string()))
+    /// }
+    /// ```
+    pub fn factory_burn_msg<A: Into<String>>(&self, sender: A) -> Result<CosmosMsg, AssetError> {
+        use prost::Message;
+
+        let msg = MsgBurn {
+            sender: sender.into(),
+            amount: Some(self.to_factory_coin()?),
+        };
+        Ok(CosmosMsg::Stargate {
+            type_url: String::from("/osmosis.tokenfactory.v1beta1.MsgBurn"),
+            value: msg.encode_to_vec().into(),
+        })
+    }
+
+    fn to_factory_coin(&self) -> Result<cosmos_sdk_proto::cosmos::base::v1beta1::Coin, AssetError> {
+        match &self.info {
+            AssetInfo::Native(denom) if denom.starts_with("factory/") => {
+                Ok(cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+                    denom: denom.clone(),
+                    amount: self.amount.to_string(),
+                })
+            }
+            AssetInfo::Native(denom) => Err(AssetError::NotTokenFactoryDenom {
+                denom: denom.clone(),
+            }),
+            AssetInfo::Cw20(_) => Err(AssetError::TokenFactoryCw20),
+        }
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
@@ -417,6 +1228,105 @@ mod tests {
         )
     }
 
+    #[test]
+    fn creating_instances_with_other_amount_types() {
+        use cosmwasm_std::Uint64;
+
+        let asset: AssetBase<Addr, Uint64> = AssetBase::native("uusd", 123456u64);
+        assert_eq!(asset.amount, Uint64::new(123456u64));
+        assert_eq!(asset.to_string(), "native:uusd:123456");
+
+        // signed amounts, e.g. for representing balance deltas
+        let delta: AssetBase<Addr, i128> = AssetBase::cw20(Addr::unchecked("mock_token"), -420i128);
+        assert_eq!(delta.info, AssetInfo::cw20(Addr::unchecked("mock_token")));
+        assert_eq!(delta.amount, -420);
+        assert_eq!(delta.to_string(), "cw20:mock_token:-420");
+
+        let unchecked: AssetBase<String, i64> =
+            AssetBase::new(AssetInfoUnchecked::native("uusd"), 69);
+        assert_eq!(unchecked.clone(), unchecked);
+    }
+
+    #[test]
+    fn replacing_amount() {
+        let asset = Asset::cw20(Addr::unchecked("mock_token"), 12345u128);
+        let replaced = asset.with_amount(67890u128);
+        assert_eq!(replaced.info, asset.info);
+        assert_eq!(replaced.amount, Uint128::new(67890));
+        assert_eq!(asset.amount, Uint128::new(12345));
+
+        let asset = Asset::native("uusd", 69u128);
+        assert_eq!(asset.with_amount(Uint128::zero()), Asset::native("uusd", 0u128));
+    }
+
+    #[test]
+    fn computing_min_received() {
+        let asset = Asset::native("uusd", 12345u128);
+        assert_eq!(asset.min_received(Decimal::zero()).unwrap(), asset);
+        assert_eq!(asset.min_received(Decimal::percent(1)).unwrap(), Asset::native("uusd", 12221u128));
+        assert_eq!(asset.min_received(Decimal::percent(100)).unwrap(), Asset::native("uusd", 0u128));
+        assert_eq!(asset.min_received(Decimal::percent(150)).unwrap(), Asset::native("uusd", 0u128));
+    }
+
+    #[test]
+    fn computing_percentage() {
+        let deposit = Asset::native("uusd", 1234u128);
+        let pool = Asset::native("uusd", 10000u128);
+        assert_eq!(deposit.percent_of(&pool).unwrap(), Decimal::from_ratio(1234u128, 100u128));
+        assert_eq!(pool.percent_of(&pool).unwrap(), Decimal::from_ratio(100u128, 1u128));
+
+        let err = deposit.percent_of(&Asset::native("uluna", 10000u128));
+        assert_eq!(
+            err,
+            Err(AssetError::AssetMismatch {
+                asset: String::from("native:uusd:1234"),
+                reference: String::from("native:uluna:10000"),
+            })
+        );
+
+        let err = deposit.percent_of(&Asset::native("uusd", 0u128));
+        assert_eq!(
+            err,
+            Err(AssetError::ZeroReferenceAmount {
+                asset: String::from("native:uusd:1234"),
+            })
+        );
+
+        // percentages too large for `Decimal` error rather than panic
+        let whale = Asset::native("uusd", 100_000_000_000_000_000_000u128);
+        let err = whale.percent_of(&Asset::native("uusd", 1u128));
+        assert!(matches!(err, Err(AssetError::Overflow(_))));
+        assert_eq!(
+            Asset::native("uusd", 1u128).percent_of(&Asset::native("uusd", u128::MAX)).unwrap(),
+            Decimal::zero()
+        );
+    }
+
+    #[test]
+    fn creating_from_balance_change() {
+        let asset = Asset::from_balance_change(
+            AssetInfo::native("uusd"),
+            Uint128::new(100),
+            Uint128::new(169),
+        )
+        .unwrap();
+        assert_eq!(asset, Asset::native("uusd", 69u128));
+
+        let err = Asset::from_balance_change(
+            AssetInfo::cw20(Addr::unchecked("mock_token")),
+            Uint128::new(169),
+            Uint128::new(100),
+        );
+        assert_eq!(
+            err,
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Sub,
+                Uint128::new(100),
+                Uint128::new(169)
+            )))
+        );
+    }
+
     #[test]
     fn casting_coin() {
         let uusd = Asset::native("uusd", 69u128);
@@ -430,11 +1340,15 @@ mod tests {
         let astro = Asset::cw20(Addr::unchecked("astro_token"), 69u128);
         assert_eq!(
             Coin::try_from(&astro), 
-            Err(StdError::generic_err("cannot cast asset cw20:astro_token:69 into cosmwasm_std::Coin"))
+            Err(AssetError::CannotCastToCoin {
+                asset: String::from("cw20:astro_token:69"),
+            })
         );
         assert_eq!(
             Coin::try_from(astro), 
-            Err(StdError::generic_err("cannot cast asset cw20:astro_token:69 into cosmwasm_std::Coin"))
+            Err(AssetError::CannotCastToCoin {
+                asset: String::from("cw20:astro_token:69"),
+            })
         );
     }
 
@@ -450,6 +1364,22 @@ mod tests {
         assert_eq!(astro == astro.clone(), true);
     }
 
+    #[test]
+    fn comparing_approx() {
+        let uusd_1 = Asset::native("uusd", 12345u128);
+        let uusd_2 = Asset::native("uusd", 12346u128);
+        let uluna = Asset::native("uluna", 12345u128);
+
+        assert_eq!(uusd_1.approx_eq(&uusd_2, 1u128), true);
+        assert_eq!(uusd_2.approx_eq(&uusd_1, 1u128), true);
+        assert_eq!(uusd_1.approx_eq(&uusd_2, 0u128), false);
+        assert_eq!(uusd_1.approx_eq(&uluna, 1u128), false);
+
+        // exact equality is unaffected by approximate comparison
+        assert_eq!(uusd_1 == uusd_2, false);
+        assert_eq!(uusd_1 == uusd_1.clone(), true);
+    }
+
     #[test]
     fn comparing_coin() {
         let uluna = Asset::native("uluna", 69u128);
@@ -469,6 +1399,11 @@ mod tests {
         assert_eq!(uusd_coin == uusd_2, false);
         assert_eq!(astro == uusd_coin, false);
         assert_eq!(uusd_coin == astro, false);
+
+        // a cw20 token never equals a coin, even if its address coincides with the coin's denom
+        let lookalike = Asset::cw20(Addr::unchecked("uusd"), 69u128);
+        assert_eq!(lookalike == uusd_coin, false);
+        assert_eq!(uusd_coin == lookalike, false);
     }
 
     #[test]
@@ -476,19 +1411,25 @@ mod tests {
         let s = "native:uusd:12345:67890";
         assert_eq!(
             AssetUnchecked::from_str(s), 
-            Err(StdError::generic_err("invalid asset format `native:uusd:12345:67890`; must be in format `native:{denom}:{amount}` or `cw20:{contract_addr}:{amount}`")),
+            Err(AssetError::InvalidAssetFormat {
+                received: String::from("native:uusd:12345:67890"),
+            }),
         );
 
         let s = "cw721:galactic_punk:1";
         assert_eq!(
             AssetUnchecked::from_str(s),
-            Err(StdError::generic_err("invalid asset type `cw721`; must be `native` or `cw20`")),
+            Err(AssetError::InvalidAssetType {
+                ty: String::from("cw721"),
+            }),
         );
 
         let s = "native:uusd:ngmi";
         assert_eq!(
             AssetUnchecked::from_str(s),
-            Err(StdError::generic_err("invalid asset amount `ngmi`; must be a 128-bit unsigned integer")),
+            Err(AssetError::InvalidAssetAmount {
+                amount: String::from("ngmi"),
+            }),
         );
 
         let s = "native:uusd:12345";
@@ -504,6 +1445,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parsing_display_round_trip() {
+        let assets = vec![
+            Asset::native("uusd", 69420u128),
+            Asset::cw20(Addr::unchecked("mock_token"), 88888u128),
+            Asset::native("uluna", 0u128),
+            Asset::native("uosmo", u128::MAX),
+        ];
+        for asset in assets {
+            let parsed = AssetUnchecked::from_str(&asset.to_string()).unwrap();
+            assert_eq!(parsed, AssetUnchecked::from(asset.clone()));
+            assert_eq!(parsed.check(&MockApi::default(), None).unwrap(), asset);
+        }
+    }
+
     #[test]
     fn to_string() {
         let asset = Asset::native("uusd", 69420u128);
@@ -511,6 +1467,36 @@ mod tests {
 
         let asset = Asset::cw20(Addr::unchecked("mock_token"), 88888u128);
         assert_eq!(asset.to_string(), String::from("cw20:mock_token:88888"));
+
+        let asset = AssetUnchecked::native("uusd", 69420u128);
+        assert_eq!(asset.to_string(), String::from("native:uusd:69420"));
+
+        let asset = AssetUnchecked::cw20("mock_token", 88888u128);
+        assert_eq!(asset.to_string(), String::from("cw20:mock_token:88888"));
+    }
+
+    #[test]
+    fn to_human_string() {
+        let asset = Asset::native("uusd", 69420u128);
+        assert_eq!(asset.to_human_string(), String::from("69420 uusd"));
+
+        let asset = Asset::cw20(Addr::unchecked("mock_token"), 88888u128);
+        assert_eq!(asset.to_human_string(), String::from("88888 mock_token"));
+    }
+
+    #[test]
+    fn hashing() {
+        use std::collections::HashSet;
+
+        let assets: HashSet<Asset> = vec![
+            Asset::native("uusd", 12345u128),
+            Asset::native("uusd", 12345u128),
+            Asset::native("uusd", 67890u128),
+            Asset::cw20(Addr::unchecked("mock_token"), 12345u128),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(assets.len(), 3);
     }
 
     #[test]
@@ -528,7 +1514,30 @@ mod tests {
         let unchecked = AssetUnchecked::new(AssetInfoUnchecked::native("uatom"), 12345u128);
         assert_eq!(
             unchecked.check(&api, Some(&["uusd", "uluna", "uosmo"])),
-            Err(StdError::generic_err("invalid denom uatom; must be uusd|uluna|uosmo")),
+            Err(AssetError::DenomNotWhitelisted {
+                denom: String::from("uatom"),
+                whitelist: String::from("uusd|uluna|uosmo"),
+            }),
+        );
+    }
+
+    #[test]
+    fn checking_nonzero() {
+        let api = MockApi::default();
+
+        let unchecked = AssetUnchecked::native("uusd", 12345u128);
+        assert_eq!(unchecked.check_nonzero(&api), Ok(Asset::native("uusd", 12345u128)));
+
+        let unchecked = AssetUnchecked::cw20("mock_token", 0u128);
+        assert_eq!(
+            unchecked.check_nonzero(&api),
+            Err(AssetError::ZeroAmount {
+                info: String::from("cw20:mock_token"),
+            }),
+        );
+        assert_eq!(
+            unchecked.check(&api, None),
+            Ok(Asset::cw20(Addr::unchecked("mock_token"), 0u128))
         );
     }
 
@@ -564,7 +1573,7 @@ mod tests {
         );
 
         let err = coin.send_msg("mock_contract", bin_msg);
-        assert_eq!(err, Err(StdError::generic_err("native coins do not have `send` method")));
+        assert_eq!(err, Err(AssetError::not_supported_for_native("send")));
 
         let msg = token.transfer_msg("alice").unwrap();
         assert_eq!(
@@ -607,7 +1616,414 @@ mod tests {
         let err = coin.transfer_from_msg("bob", "charlie");
         assert_eq!(
             err,
-            Err(StdError::generic_err("native coins do not have `transfer_from` method"))
+            Err(AssetError::not_supported_for_native("transfer_from"))
+        );
+    }
+
+    #[test]
+    fn creating_allowance_messages() {
+        let token = Asset::cw20(Addr::unchecked("mock_token"), 123456u128);
+        let coin = Asset::native("uusd", 123456u128);
+
+        assert_eq!(
+            token.increase_allowance_msg("bob", Some(Expiration::AtHeight(12345))).unwrap(),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: String::from("mock_token"),
+                msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: String::from("bob"),
+                    amount: Uint128::new(123456),
+                    expires: Some(Expiration::AtHeight(12345))
+                })
+                .unwrap(),
+                funds: vec![]
+            })
+        );
+        assert_eq!(
+            token.decrease_allowance_msg("bob", None).unwrap(),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: String::from("mock_token"),
+                msg: to_binary(&Cw20ExecuteMsg::DecreaseAllowance {
+                    spender: String::from("bob"),
+                    amount: Uint128::new(123456),
+                    expires: None
+                })
+                .unwrap(),
+                funds: vec![]
+            })
+        );
+
+        assert_eq!(
+            coin.increase_allowance_msg("bob", None),
+            Err(AssetError::not_supported_for_native("increase_allowance"))
+        );
+        assert_eq!(
+            coin.decrease_allowance_msg("bob", None),
+            Err(AssetError::not_supported_for_native("decrease_allowance"))
+        );
+    }
+
+    #[test]
+    fn querying_balance() {
+        use super::super::testing::mock_dependencies;
+
+        let mut deps = mock_dependencies();
+        deps.querier.set_base_balances("alice", &[Coin::new(12345, "uusd")]);
+        deps.querier.set_cw20_balance("mock_token", "bob", 67890);
+
+        let asset1 = Asset::native("uusd", 1u128);
+        let balance1 = asset1.query_balance(&deps.as_ref().querier, "alice").unwrap();
+        assert_eq!(balance1, Uint128::new(12345));
+
+        let asset2 = Asset::cw20(Addr::unchecked("mock_token"), 1u128);
+        let balance2 = asset2.query_balance(&deps.as_ref().querier, "bob").unwrap();
+        assert_eq!(balance2, Uint128::new(67890));
+    }
+
+    #[test]
+    fn creating_burn_messages() {
+        let token = Asset::cw20(Addr::unchecked("mock_token"), 123456u128);
+        let coin = Asset::native("uusd", 123456u128);
+
+        assert_eq!(
+            token.burn_msg().unwrap(),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: String::from("mock_token"),
+                msg: to_binary(&Cw20ExecuteMsg::Burn {
+                    amount: Uint128::new(123456)
+                })
+                .unwrap(),
+                funds: vec![]
+            })
+        );
+
+        assert_eq!(
+            coin.burn_msg(),
+            Err(AssetError::not_supported_for_native("burn"))
+        );
+    }
+
+    /// A semi-fungible CW1155 token, which is not one of the built-in variants
+    struct MockCw1155Asset {
+        contract_addr: String,
+        token_id: String,
+        amount: Uint128,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum MockCw1155ExecuteMsg {
+        SendFrom {
+            from: String,
+            to: String,
+            token_id: String,
+            value: Uint128,
+        },
+    }
+
+    impl AssetLike for MockCw1155Asset {
+        fn transfer_msg(&self, to: &str) -> Result<CosmosMsg, AssetError> {
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: self.contract_addr.clone(),
+                msg: to_binary(&MockCw1155ExecuteMsg::SendFrom {
+                    from: String::from("sender"),
+                    to: String::from(to),
+                    token_id: self.token_id.clone(),
+                    value: self.amount,
+                })?,
+                funds: vec![],
+            }))
+        }
+
+        fn query_balance(
+            &self,
+            _querier: &QuerierWrapper,
+            _address: &str,
+        ) -> Result<Uint128, AssetError> {
+            Ok(self.amount)
+        }
+    }
+
+    #[test]
+    fn dispatching_through_trait() {
+        use super::super::testing::mock_dependencies;
+
+        let mut deps = mock_dependencies();
+        deps.querier.set_base_balances("alice", &[Coin::new(12345, "uusd")]);
+
+        let coin = Asset::native("uusd", 123456u128);
+        let sft = MockCw1155Asset {
+            contract_addr: String::from("mock_cw1155"),
+            token_id: String::from("sword"),
+            amount: Uint128::new(3),
+        };
+        let more_coin = Asset::native("uusd", 1u128);
+        let assets: Vec<&dyn AssetLike> = vec![&coin, &sft, &more_coin];
+
+        assert_eq!(assets[0].native_coin(), Some(Coin::new(123456, "uusd")));
+        assert_eq!(assets[1].native_coin(), None);
+
+        let msgs = crate::transfer_all(&assets, "alice").unwrap();
+        assert_eq!(
+            msgs,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: String::from("alice"),
+                    amount: vec![Coin::new(123457, "uusd")],
+                }),
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: String::from("mock_cw1155"),
+                    msg: to_binary(&MockCw1155ExecuteMsg::SendFrom {
+                        from: String::from("sender"),
+                        to: String::from("alice"),
+                        token_id: String::from("sword"),
+                        value: Uint128::new(3)
+                    })
+                    .unwrap(),
+                    funds: vec![]
+                }),
+            ]
+        );
+
+        let querier = deps.as_ref().querier;
+        assert_eq!(assets[0].query_balance(&querier, "alice"), Ok(Uint128::new(12345)));
+        assert_eq!(assets[1].query_balance(&querier, "alice"), Ok(Uint128::new(3)));
+    }
+
+    #[test]
+    fn creating_transfer_to_many_messages() {
+        let token = Asset::cw20(Addr::unchecked("mock_token"), 100u128);
+        let coin = Asset::native("uusd", 100u128);
+        let recipients =
+            vec![(String::from("alice"), Uint128::new(60)), (String::from("bob"), Uint128::new(40))];
+
+        for asset in vec![token, coin.clone()] {
+            let msgs = asset.transfer_to_many_msg(&recipients).unwrap();
+            assert_eq!(
+                msgs,
+                vec![
+                    Asset::new(asset.info.clone(), 60u128).transfer_msg("alice").unwrap(),
+                    Asset::new(asset.info.clone(), 40u128).transfer_msg("bob").unwrap(),
+                ]
+            );
+        }
+
+        let recipients = vec![(String::from("alice"), Uint128::new(60))];
+        assert_eq!(
+            coin.transfer_to_many_msg(&recipients),
+            Err(AssetError::SplitMismatch {
+                asset: String::from("native:uusd:100"),
+                total: Uint128::new(60),
+            })
+        );
+
+        assert_eq!(
+            coin.transfer_to_many_msg(&[]),
+            Err(AssetError::SplitMismatch {
+                asset: String::from("native:uusd:100"),
+                total: Uint128::new(0),
+            })
+        );
+        assert_eq!(Asset::native("uusd", 0u128).transfer_to_many_msg(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn creating_refund_messages() {
+        let token = Asset::cw20(Addr::unchecked("mock_token"), 123456u128);
+        let coin = Asset::native("uusd", 123456u128);
+
+        assert_eq!(token.refund_msg("alice").unwrap(), token.transfer_msg("alice").unwrap());
+        assert_eq!(coin.refund_msg("alice").unwrap(), coin.transfer_msg("alice").unwrap());
+    }
+
+    #[test]
+    fn checked_adding_and_subtracting() {
+        let a = Asset::native("uusd", 12345u128);
+        let b = Asset::native("uusd", 67890u128);
+        let token = Asset::cw20(Addr::unchecked("token"), 12345u128);
+
+        assert_eq!(a.checked_add(&b), Ok(Asset::native("uusd", 80235u128)));
+        assert_eq!(b.checked_sub(&a), Ok(Asset::native("uusd", 55545u128)));
+
+        assert_eq!(
+            a.checked_add(&token),
+            Err(AssetError::IncompatibleOperands {
+                operation: String::from("add"),
+                left: String::from("native:uusd"),
+                right: String::from("cw20:token"),
+            })
+        );
+        assert_eq!(
+            a.checked_sub(&token),
+            Err(AssetError::IncompatibleOperands {
+                operation: String::from("subtract"),
+                left: String::from("native:uusd"),
+                right: String::from("cw20:token"),
+            })
+        );
+
+        assert_eq!(
+            a.checked_sub(&b),
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Sub,
+                Uint128::new(12345),
+                Uint128::new(67890)
+            )))
+        );
+        assert_eq!(
+            Asset::native("uusd", u128::MAX).checked_add(&Asset::native("uusd", 1u128)),
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Add,
+                Uint128::new(u128::MAX),
+                Uint128::new(1)
+            )))
+        );
+    }
+
+    #[test]
+    fn multiplying_rounded() {
+        let asset = Asset::native("uusd", 12345u128);
+
+        let floor = asset.checked_mul_rounded(Decimal::permille(3), RoundingMode::Floor);
+        assert_eq!(floor, Ok(Asset::native("uusd", 37u128)));
+        let ceil = asset.checked_mul_rounded(Decimal::permille(3), RoundingMode::Ceil);
+        assert_eq!(ceil, Ok(Asset::native("uusd", 38u128)));
+
+        // exact products are not rounded up
+        let ceil = asset.checked_mul_rounded(Decimal::percent(200), RoundingMode::Ceil);
+        assert_eq!(ceil, Ok(Asset::native("uusd", 24690u128)));
+    }
+
+    #[test]
+    fn multiplying_by_ratio() {
+        let asset = Asset::native("uusd", 12345u128);
+
+        // rounded down
+        assert_eq!(asset.checked_mul_ratio(1u128, 7u128), Ok(Asset::native("uusd", 1763u128)));
+        assert_eq!(asset.checked_mul_ratio(3u128, 7u128), Ok(Asset::native("uusd", 5290u128)));
+        assert_eq!(asset.checked_mul(Decimal::permille(3)), Ok(Asset::native("uusd", 37u128)));
+
+        assert_eq!(
+            asset.checked_mul_ratio(1u128, 0u128),
+            Err(AssetError::ZeroDenominator {
+                asset: String::from("native:uusd:12345"),
+            })
+        );
+
+        // intermediate product exceeds `Uint128::MAX`, but the result does not
+        let large = Asset::native("uusd", u128::MAX);
+        assert_eq!(
+            large.checked_mul_ratio(u128::MAX - 1, u128::MAX),
+            Ok(Asset::native("uusd", u128::MAX - 1))
+        );
+        assert_eq!(large.checked_mul_ratio(3u128, 3u128), Ok(large.clone()));
+
+        // result exceeds `Uint128::MAX`
+        assert_eq!(
+            large.checked_mul_ratio(3u128, 2u128),
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Mul,
+                Uint128::new(u128::MAX),
+                Uint128::new(3)
+            )))
+        );
+    }
+
+    #[test]
+    fn adding_and_subtracting() {
+        let a = Asset::native("uusd", 12345u128);
+        let b = Asset::native("uusd", 2345u128);
+
+        assert_eq!(&a + &b, Asset::native("uusd", 14690u128));
+        assert_eq!(&a - &b, Asset::native("uusd", 10000u128));
+        assert_eq!(a + b, Asset::native("uusd", 14690u128));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add assets of different types: native:uusd vs cw20:token")]
+    fn adding_mismatched_assets() {
+        let token = Asset::cw20(Addr::unchecked("token"), 12345u128);
+        let _ = Asset::native("uusd", 12345u128) + token;
+    }
+
+    #[test]
+    #[should_panic]
+    fn adding_overflow() {
+        let _ = Asset::native("uusd", u128::MAX) + Asset::native("uusd", 1u128);
+    }
+
+    #[test]
+    fn deducting_coin() {
+        let uusd = Asset::native("uusd", 12345u128);
+
+        let deducted = uusd.deduct_coin(&Coin::new(2345, "uusd")).unwrap();
+        assert_eq!(deducted, Asset::native("uusd", 10000u128));
+
+        let err = uusd.deduct_coin(&Coin::new(67890, "uusd"));
+        assert_eq!(
+            err,
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Sub,
+                Uint128::new(12345),
+                Uint128::new(67890)
+            )))
+        );
+
+        let err = uusd.deduct_coin(&Coin::new(2345, "uluna"));
+        assert_eq!(
+            err,
+            Err(AssetError::DeductDenomMismatch {
+                coin: String::from("2345uluna"),
+                asset: String::from("native:uusd:12345"),
+            })
+        );
+
+        let astro = Asset::cw20(Addr::unchecked("astro_token"), 12345u128);
+        let err = astro.deduct_coin(&Coin::new(2345, "uusd"));
+        assert_eq!(
+            err,
+            Err(AssetError::DeductFromCw20 {
+                coin: String::from("2345uusd"),
+                asset: String::from("cw20:astro_token:12345"),
+            })
+        );
+    }
+}
+
+#[cfg(all(test, feature = "cw20-balance"))]
+mod tests_cw20_balance {
+    use super::*;
+    use cw20::{Balance, Cw20CoinVerified};
+
+    #[test]
+    fn casting_cw20_balance() {
+        let uusd = Asset::native("uusd", 69u128);
+        let uusd_balance = Balance::from(vec![Coin::new(69, "uusd")]);
+        assert_eq!(Balance::from(&uusd), uusd_balance);
+        assert_eq!(Asset::try_from(&uusd_balance).unwrap(), uusd);
+        assert_eq!(Asset::try_from(Balance::from(uusd.clone())).unwrap(), uusd);
+
+        let astro = Asset::cw20(Addr::unchecked("astro_token"), 69u128);
+        let astro_balance = Balance::from(Cw20CoinVerified {
+            address: Addr::unchecked("astro_token"),
+            amount: Uint128::new(69),
+        });
+        assert_eq!(Balance::from(&astro), astro_balance);
+        assert_eq!(Asset::try_from(&astro_balance).unwrap(), astro);
+
+        let balance = Balance::from(vec![Coin::new(69, "uusd"), Coin::new(420, "uluna")]);
+        assert_eq!(
+            Asset::try_from(balance),
+            Err(AssetError::CannotCastNativeBalance {
+                count: 2,
+            })
+        );
+
+        let balance = Balance::from(Vec::<Coin>::new());
+        assert_eq!(
+            Asset::try_from(balance),
+            Err(AssetError::CannotCastNativeBalance {
+                count: 0,
+            })
         );
     }
 }
@@ -665,3 +2081,54 @@ mod tests_astroport {
         assert_eq!(legacy_asset_3 == asset, false);
     }
 }
+
+#[cfg(all(test, feature = "token-factory"))]
+mod tests_token_factory {
+    use super::*;
+
+    /// Protobuf encoding of
+    /// `{ sender: "alice", amount: { denom: "factory/alice/foo", amount: "100" } }`
+    fn expected_bytes() -> Vec<u8> {
+        let mut bytes = vec![0x0a, 0x05];
+        bytes.extend(b"alice");
+        bytes.extend(&[0x12, 0x18, 0x0a, 0x11]);
+        bytes.extend(b"factory/alice/foo");
+        bytes.extend(&[0x12, 0x03]);
+        bytes.extend(b"100");
+        bytes
+    }
+
+    #[test]
+    fn creating_factory_messages() {
+        let asset = Asset::native("factory/alice/foo", 100u128);
+
+        assert_eq!(
+            asset.factory_mint_msg("alice").unwrap(),
+            CosmosMsg::Stargate {
+                type_url: String::from("/osmosis.tokenfactory.v1beta1.MsgMint"),
+                value: Binary::from(expected_bytes()),
+            }
+        );
+        assert_eq!(
+            asset.factory_burn_msg("alice").unwrap(),
+            CosmosMsg::Stargate {
+                type_url: String::from("/osmosis.tokenfactory.v1beta1.MsgBurn"),
+                value: Binary::from(expected_bytes()),
+            }
+        );
+
+        let asset = Asset::native("uusd", 100u128);
+        assert_eq!(
+            asset.factory_mint_msg("alice"),
+            Err(AssetError::NotTokenFactoryDenom {
+                denom: String::from("uusd"),
+            })
+        );
+
+        let asset = Asset::cw20(Addr::unchecked("mock_token"), 100u128);
+        assert_eq!(
+            asset.factory_burn_msg("alice"),
+            Err(AssetError::TokenFactoryCw20)
+        );
+    }
+}
@@ -3,20 +3,27 @@ use std::str::FromStr;
 
 use cosmwasm_std::{
     to_binary, Addr, Api, BalanceResponse, BankQuery, QuerierWrapper, QueryRequest, StdError,
-    StdResult, Uint128, WasmQuery,
+    Uint128, WasmQuery,
 };
-use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20QueryMsg};
+use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::asset::Asset;
+use super::error::AssetError;
+
 /// Represents the type of an fungible asset
 ///
 /// Each **asset info** instance can be one of two variants:
 ///
 /// - CW20 tokens. To create an **asset info** instance of this type, provide the contract address.
 /// - Native SDK coins. To create an **asset info** instance of this type, provide the denomination.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+///
+/// NOTE: Constructing an instance is infallible and never panics, regardless of the input; the
+/// constructors merely store the value provided. Validation only happens upon `check`, which may
+/// reject the instance.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AssetInfoBase<T> {
     Cw20(T),
@@ -50,6 +57,21 @@ impl<T> AssetInfoBase<T> {
     pub fn native<A: Into<String>>(denom: A) -> Self {
         AssetInfoBase::Native(denom.into())
     }
+
+    /// Return the denomination if the asset is of the _native_ variant, or `None` otherwise
+    ///
+    /// ```rust
+    /// use cw_asset::AssetInfo;
+    ///
+    /// let info = AssetInfo::native("uusd");
+    /// let denom = info.denom();  // should be `Some("uusd")`
+    /// ```
+    pub fn denom(&self) -> Option<&String> {
+        match self {
+            AssetInfoBase::Native(denom) => Some(denom),
+            AssetInfoBase::Cw20(_) => None,
+        }
+    }
 }
 
 /// Represents an **asset info** instance that may contain unverified data; to be used in messages
@@ -58,22 +80,64 @@ pub type AssetInfoUnchecked = AssetInfoBase<String>;
 pub type AssetInfo = AssetInfoBase<Addr>;
 
 impl FromStr for AssetInfoUnchecked {
-    type Err = StdError;
+    type Err = AssetError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let words: Vec<&str> = s.split(":").collect();
         if words.len() != 2 {
-            return Err(StdError::generic_err(
-                format!("invalid asset info format `{}`; must be in format `native:{{denom}}` or `cw20:{{contract_addr}}`", s)
-            ));
+            return Err(AssetError::InvalidAssetInfoFormat {
+                received: s.to_string(),
+            });
         }
 
         match words[0] {
             "native" => Ok(AssetInfoUnchecked::Native(String::from(words[1]))),
             "cw20" => Ok(AssetInfoUnchecked::Cw20(String::from(words[1]))),
-            ty => Err(StdError::generic_err(
-                format!("invalid asset type `{}`; must be `native` or `cw20`", ty)
-            ))
+            ty => Err(AssetError::InvalidAssetType {
+                ty: ty.to_string(),
+            }),
+        }
+    }
+}
+
+impl AssetInfoUnchecked {
+    /// Create an **asset info** instance of the _native_ variant from arbitrary input, trimming
+    /// surrounding whitespace and dropping any control characters
+    ///
+    /// Like other constructors, this never fails or panics; the result should still be validated
+    /// with `check`.
+    ///
+    /// ```rust
+    /// use cw_asset::AssetInfoUnchecked;
+    ///
+    /// let info = AssetInfoUnchecked::native_lossy(" uusd\n");  // should be `native:uusd`
+    /// ```
+    pub fn native_lossy(denom: &str) -> Self {
+        AssetInfoUnchecked::Native(denom.trim().chars().filter(|c| !c.is_control()).collect())
+    }
+
+    /// Parse an **asset info** instance from a storage namespace generated by
+    /// [`AssetInfo::storage_namespace`]
+    ///
+    /// This is useful in migrations, where the asset a sub-map belongs to needs to be recovered
+    /// from the namespace. The resulting asset info is unchecked and should be validated.
+    pub fn from_storage_namespace(namespace: &str) -> Result<Self, AssetError> {
+        let err = || AssetError::InvalidStorageNamespace {
+            namespace: String::from(namespace),
+        };
+
+        let mut parts = namespace.splitn(3, ':');
+        let ty = parts.next().ok_or_else(err)?;
+        let len: usize = parts.next().and_then(|len| len.parse().ok()).ok_or_else(err)?;
+        let value = parts.next().ok_or_else(err)?;
+        if value.len() != len {
+            return Err(err());
+        }
+
+        match ty {
+            "native" => Ok(AssetInfoUnchecked::Native(String::from(value))),
+            "cw20" => Ok(AssetInfoUnchecked::Cw20(String::from(value))),
+            _ => Err(err()),
         }
     }
 }
@@ -91,8 +155,9 @@ impl AssetInfoUnchecked {
     /// Validate data contained in an _unchecked_ **asset info** instance; return a new _checked_
     /// **asset info** instance:
     /// * For CW20 tokens, assert the contract address is valid
-    /// * For SDK coins, assert that the denom is included in a given whitelist; skip if the 
-    ///   whitelist is not provided
+    /// * For SDK coins, assert that the denom is valid according to the Cosmos SDK's rules, i.e.
+    ///   3-128 characters long and matching `[a-zA-Z][a-zA-Z0-9/:._-]*`, and that it is included in
+    ///   a given whitelist; skip the latter if the whitelist is not provided
     /// 
     /// 
     /// ```rust
@@ -106,7 +171,11 @@ impl AssetInfoUnchecked {
     ///     }
     /// }
     /// ```
-    pub fn check(&self, api: &dyn Api, optional_whitelist: Option<&[&str]>) -> StdResult<AssetInfo> {
+    pub fn check(
+        &self,
+        api: &dyn Api,
+        optional_whitelist: Option<&[&str]>,
+    ) -> Result<AssetInfo, AssetError> {
         Ok(match self {
             AssetInfoUnchecked::Cw20(contract_addr) => {
                 // NOTE: We cast all contract addresses to lowercase, in order to prevent 
@@ -114,29 +183,363 @@ impl AssetInfoUnchecked {
                 AssetInfo::Cw20(api.addr_validate(&contract_addr.to_lowercase())?)
             }
             AssetInfoUnchecked::Native(denom) => {
+                validate_denom(denom)?;
                 if let Some(whitelist) = optional_whitelist {
                     if !whitelist.contains(&&denom[..]) {
-                        return Err(StdError::generic_err(
-                            format!("invalid denom {}; must be {}", denom, whitelist.join("|"))
-                        ));
+                        return Err(AssetError::DenomNotWhitelisted {
+                            denom: denom.clone(),
+                            whitelist: whitelist.join("|"),
+                        });
                     }
                 }
                 AssetInfo::Native(denom.clone())
             }
         })
     }
+
+    /// Validate data contained in an _unchecked_ **asset info** instance against a blocklist;
+    /// return a new _checked_ **asset info** instance:
+    /// * For CW20 tokens, assert the contract address is valid
+    /// * For SDK coins, assert that the denom is _not_ included in the given blocklist
+    ///
+    /// This complements the whitelist accepted by [`check`](Self::check); to enforce both, run the
+    /// two checks one after the other:
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Api, StdResult};
+    /// use cw_asset::{AssetInfo, AssetInfoUnchecked};
+    ///
+    /// fn validate_asset_info(api: &dyn Api, info_unchecked: &AssetInfoUnchecked) -> StdResult<AssetInfo> {
+    ///     info_unchecked.check_not_blocked(api, &["ustake"])?;
+    ///     Ok(info_unchecked.check(api, Some(&["uatom", "uluna", "ustake"]))?)
+    /// }
+    /// ```
+    pub fn check_not_blocked(
+        &self,
+        api: &dyn Api,
+        blocked: &[&str],
+    ) -> Result<AssetInfo, AssetError> {
+        if let AssetInfoUnchecked::Native(denom) = self {
+            if blocked.contains(&&denom[..]) {
+                return Err(AssetError::DenomBlocked {
+                    denom: denom.clone(),
+                });
+            }
+        }
+        self.check(api, None)
+    }
+
+    /// Similar to `check`, but first bring native denoms into a canonical form, mirroring the
+    /// lowercasing applied to CW20 contract addresses
+    ///
+    /// The rule is: an `ibc/` prefix, in any casing, is rewritten to lowercase `ibc/`, while the
+    /// hash that follows is preserved as is. Other denoms are left untouched. This ensures e.g.
+    /// `IBC/27394F...` and `ibc/27394F...` are stored under the same key. To also ignore the casing
+    /// of the hash when comparing two denoms, use [`AssetInfo::same_asset`].
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Api, StdResult};
+    /// use cw_asset::{AssetInfo, AssetInfoUnchecked};
+    ///
+    /// fn validate_asset_info(api: &dyn Api) -> StdResult<AssetInfo> {
+    ///     // should be `native:ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2`
+    ///     let info = AssetInfoUnchecked::native(
+    ///         "IBC/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+    ///     )
+    ///     .check_normalized(api, None)?;
+    ///     Ok(info)
+    /// }
+    /// ```
+    pub fn check_normalized(
+        &self,
+        api: &dyn Api,
+        optional_whitelist: Option<&[&str]>,
+    ) -> Result<AssetInfo, AssetError> {
+        match self {
+            AssetInfoUnchecked::Native(denom) => {
+                AssetInfoUnchecked::Native(normalize_denom(denom)).check(api, optional_whitelist)
+            }
+            AssetInfoUnchecked::Cw20(_) => self.check(api, optional_whitelist),
+        }
+    }
 }
 
-impl fmt::Display for AssetInfo {
+impl<T: fmt::Display> fmt::Display for AssetInfoBase<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            AssetInfo::Cw20(contract_addr) => write!(f, "cw20:{}", contract_addr),
-            AssetInfo::Native(denom) => write!(f, "native:{}", denom),
+            AssetInfoBase::Cw20(contract_addr) => write!(f, "cw20:{}", contract_addr),
+            AssetInfoBase::Native(denom) => write!(f, "native:{}", denom),
+        }
+    }
+}
+
+/// Assert that a denom conforms to the Cosmos SDK's rules, i.e. is 3 to 128 characters long and
+/// matches the regex `[a-zA-Z][a-zA-Z0-9/:._-]*`
+fn validate_denom(denom: &str) -> Result<(), AssetError> {
+    let mut chars = denom.chars();
+    let starts_with_letter = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic());
+    let valid_chars =
+        chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | ':' | '.' | '_' | '-'));
+    if !(3..=128).contains(&denom.len()) || !starts_with_letter || !valid_chars {
+        return Err(AssetError::InvalidDenom {
+            denom: denom.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Return the hash of an IBC denom, i.e. the part following an `ibc/` prefix of any casing; `None`
+/// if the denom is not an IBC denom
+fn ibc_hash(denom: &str) -> Option<&str> {
+    match denom.get(..4) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("ibc/") => Some(&denom[4..]),
+        _ => None,
+    }
+}
+
+/// Bring an IBC denom into its canonical form, i.e. rewrite an `ibc/` prefix of any casing to
+/// lowercase, preserving the hash that follows; other denoms are returned unchanged
+fn normalize_denom(denom: &str) -> String {
+    match ibc_hash(denom) {
+        Some(hash) => format!("ibc/{}", hash),
+        None => denom.to_string(),
+    }
+}
+
+/// Native coins are ordered before CW20 tokens; assets of the same type are ordered by denom or
+/// contract address, compared lexicographically
+impl<T: Ord> PartialOrd for AssetInfoBase<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for AssetInfoBase<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+        match (self, other) {
+            (AssetInfoBase::Native(a), AssetInfoBase::Native(b)) => a.cmp(b),
+            (AssetInfoBase::Cw20(a), AssetInfoBase::Cw20(b)) => a.cmp(b),
+            (AssetInfoBase::Native(_), AssetInfoBase::Cw20(_)) => Ordering::Less,
+            (AssetInfoBase::Cw20(_), AssetInfoBase::Native(_)) => Ordering::Greater,
         }
     }
 }
 
+/// Implemented by third-party asset info types that can be cast into an [`AssetInfo`], allowing
+/// them to be compared against one without enabling a dedicated feature
+///
+/// ```rust
+/// use cosmwasm_std::Addr;
+/// use cw_asset::{AssetInfo, LegacyAssetInfo};
+///
+/// struct MyAssetInfo {
+///     denom: String,
+/// }
+///
+/// impl LegacyAssetInfo for MyAssetInfo {
+///     fn as_cw_asset_info(&self) -> AssetInfo {
+///         AssetInfo::native(&self.denom)
+///     }
+/// }
+///
+/// let legacy_info = MyAssetInfo { denom: String::from("uusd") };
+/// let matches = AssetInfo::native("uusd").matches_legacy(&legacy_info);  // should be true
+/// ```
+pub trait LegacyAssetInfo {
+    /// Cast the legacy type into an **asset info** instance
+    fn as_cw_asset_info(&self) -> AssetInfo;
+}
+
+/// Declares pairs of **asset info** instances that represent the same underlying token, e.g. a
+/// native alias and its `ibc/...` denom; used by
+/// [`AssetList::merge_equivalents`](crate::AssetList::merge_equivalents)
+///
+/// ```rust
+/// use cw_asset::{AssetInfo, EquivalenceMap};
+///
+/// let mut map = EquivalenceMap::new();
+/// map.insert(
+///     AssetInfo::native("ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"),
+///     AssetInfo::native("uatom"),
+/// );
+///
+/// let canonical = map.canonical(&AssetInfo::native("uatom"));  // should be uatom itself
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EquivalenceMap {
+    aliases: Vec<(AssetInfo, AssetInfo)>,
+}
+
+impl EquivalenceMap {
+    /// Create a new, empty equivalence map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare `alias` to be equivalent to `canonical`, the representative that equivalent assets
+    /// are collapsed into
+    pub fn insert(&mut self, alias: AssetInfo, canonical: AssetInfo) -> &mut Self {
+        self.aliases.retain(|(existing, _)| *existing != alias);
+        self.aliases.push((alias, canonical));
+        self
+    }
+
+    /// Return the canonical representative of the given asset info; if no equivalence has been
+    /// declared for it, it is its own representative
+    pub fn canonical<'a>(&'a self, info: &'a AssetInfo) -> &'a AssetInfo {
+        self.aliases
+            .iter()
+            .find(|(alias, _)| alias == info)
+            .map(|(_, canonical)| canonical)
+            .unwrap_or(info)
+    }
+}
+
+/// The class of an asset, as determined by an [`AssetClassifier`]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetClass {
+    Stable,
+    Lp,
+    Governance,
+    Other,
+}
+
+/// Classifies assets according to predicates registered by the caller, e.g. naming conventions of
+/// LP token denoms, or sets of known contract addresses
+///
+/// Predicates are evaluated in the order they are registered; the class of the first matching
+/// predicate is returned. Assets matching none of the predicates are classified as
+/// [`AssetClass::Other`].
+///
+/// ```rust
+/// use cosmwasm_std::Addr;
+/// use cw_asset::{AssetClass, AssetClassifier, AssetInfo};
+///
+/// let mut classifier = AssetClassifier::new();
+/// classifier
+///     .register(AssetClass::Stable, |info| info.is_one_of(&["uusd"], &[]))
+///     .register(AssetClass::Lp, |info| match info {
+///         AssetInfo::Native(denom) => denom.starts_with("gamm/pool/"),
+///         AssetInfo::Cw20(_) => false,
+///     });
+///
+/// let class = AssetInfo::native("gamm/pool/1").classify(&classifier);  // should be `Lp`
+/// ```
+#[derive(Default)]
+pub struct AssetClassifier {
+    predicates: Vec<(AssetClass, Predicate)>,
+}
+
+type Predicate = Box<dyn Fn(&AssetInfo) -> bool>;
+
+impl AssetClassifier {
+    /// Create a new classifier without any predicate
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a predicate; assets for which it returns `true` are of the given class
+    pub fn register<F: Fn(&AssetInfo) -> bool + 'static>(
+        &mut self,
+        class: AssetClass,
+        predicate: F,
+    ) -> &mut Self {
+        self.predicates.push((class, Box::new(predicate)));
+        self
+    }
+}
+
+/// Display metadata of a token, e.g. as returned by a CW20 `TokenInfo` query
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TokenMeta {
+    /// The token's ticker symbol
+    pub symbol: String,
+    /// Number of decimal places the token's amount is displayed with
+    pub decimals: u8,
+}
+
+impl From<TokenInfoResponse> for TokenMeta {
+    fn from(response: TokenInfoResponse) -> Self {
+        Self {
+            symbol: response.symbol,
+            decimals: response.decimals,
+        }
+    }
+}
+
+/// An **asset info** instance bundled with its display metadata; created by
+/// [`AssetInfo::with_meta`]
+///
+/// NOTE: Two instances are considered equal if their asset infos are equal, regardless of their
+/// metadata.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+pub struct AssetInfoWithMeta {
+    /// Specifies the asset's type (CW20 or native)
+    pub info: AssetInfo,
+    /// Specifies the asset's display metadata
+    pub meta: TokenMeta,
+}
+
+impl PartialEq for AssetInfoWithMeta {
+    fn eq(&self, other: &Self) -> bool {
+        self.info == other.info
+    }
+}
+
+impl fmt::Display for AssetInfoWithMeta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.meta.symbol, self.info)
+    }
+}
+
+/// The query message a balance router contract must accept in order to be used with
+/// [`AssetInfo::query_balance_via`]
+///
+/// Serialized as `{"balance":{"asset_info":{"cw20":"..."},"address":"..."}}`; the router is expected
+/// to respond with a [`cw20::BalanceResponse`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceRouterQueryMsg {
+    Balance {
+        asset_info: AssetInfoUnchecked,
+        address: String,
+    },
+}
+
+/// The result of a balance query, echoing the asset and address that were queried alongside the
+/// balance; returned by [`AssetInfo::query_balance_detailed`]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BalanceQueryResult {
+    /// The asset whose balance was queried
+    pub info: AssetInfo,
+    /// The address whose balance was queried
+    pub address: Addr,
+    /// The balance returned by the query
+    pub balance: Uint128,
+}
+
+const NATIVE_BYTES_TAG: u8 = 0;
+const CW20_BYTES_TAG: u8 = 1;
+
 impl AssetInfo {
+    /// Return the contract address if the asset is of the _CW20_ variant, or `None` otherwise
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Addr;
+    /// use cw_asset::AssetInfo;
+    ///
+    /// let info = AssetInfo::cw20(Addr::unchecked("token_addr"));
+    /// let contract_addr = info.cw20_address();  // should be `Some("token_addr")`
+    /// ```
+    pub fn cw20_address(&self) -> Option<&Addr> {
+        match self {
+            AssetInfo::Cw20(contract_addr) => Some(contract_addr),
+            AssetInfo::Native(_) => None,
+        }
+    }
+
     /// Query an address' balance of the asset
     ///
     /// ```rust
@@ -145,14 +548,14 @@ impl AssetInfo {
     ///
     /// fn query_uusd_balance(deps: Deps, account_addr: &Addr) -> StdResult<Uint128> {
     ///     let info = AssetInfo::native("uusd");
-    ///     info.query_balance(&deps.querier, "account_addr")
+    ///     Ok(info.query_balance(&deps.querier, "account_addr")?)
     /// }
     /// ```
     pub fn query_balance<T: Into<String>>(
         &self,
         querier: &QuerierWrapper,
         address: T,
-    ) -> StdResult<Uint128> {
+    ) -> Result<Uint128, AssetError> {
         match self {
             AssetInfo::Cw20(contract_addr) => {
                 let response: Cw20BalanceResponse =
@@ -174,6 +577,339 @@ impl AssetInfo {
             }
         }
     }
+
+    /// Return whether the asset info is equal to a legacy asset info type implementing
+    /// [`LegacyAssetInfo`]
+    pub fn matches_legacy<T: LegacyAssetInfo>(&self, other: &T) -> bool {
+        *self == other.as_cw_asset_info()
+    }
+
+    /// Classify the asset using the predicates registered in the given classifier
+    pub fn classify(&self, classifier: &AssetClassifier) -> AssetClass {
+        classifier
+            .predicates
+            .iter()
+            .find(|(_, predicate)| predicate(self))
+            .map(|(class, _)| *class)
+            .unwrap_or(AssetClass::Other)
+    }
+
+    /// Create an **asset** instance of this asset info and the given amount
+    ///
+    /// ```rust
+    /// use cw_asset::AssetInfo;
+    ///
+    /// let uusd = AssetInfo::native("uusd");
+    /// let asset = uusd.with_amount(12345u128);  // should be `native:uusd:12345`
+    /// ```
+    pub fn with_amount<A: Into<Uint128>>(&self, amount: A) -> Asset {
+        Asset::new(self.clone(), amount)
+    }
+
+    /// Bundle the asset info with display metadata, e.g. that has already been fetched from the
+    /// token contract, so that it does not need to be queried again for display
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Addr;
+    /// use cw_asset::{AssetInfo, TokenMeta};
+    ///
+    /// let info = AssetInfo::cw20(Addr::unchecked("token_addr")).with_meta(TokenMeta {
+    ///     symbol: String::from("MARS"),
+    ///     decimals: 6,
+    /// });
+    ///
+    /// let s = info.to_string();  // should be "MARS (cw20:token_addr)"
+    /// ```
+    pub fn with_meta(self, meta: TokenMeta) -> AssetInfoWithMeta {
+        AssetInfoWithMeta {
+            info: self,
+            meta,
+        }
+    }
+
+    /// Return a namespace string derived from the asset info, e.g. for keying a `cw-storage-plus`
+    /// map by asset
+    ///
+    /// The namespace is in the format `{type}:{length}:{denom_or_address}`, where `length` is the
+    /// length of the denom or address in bytes. The length prefix guarantees that no namespace is a
+    /// prefix of another, so the namespaces of different assets never collide, whatever characters
+    /// their denoms contain. This format is stable and will not change in future versions; it can be
+    /// parsed back using [`AssetInfoUnchecked::from_storage_namespace`].
+    ///
+    /// ```rust
+    /// use cw_asset::AssetInfo;
+    ///
+    /// let namespace = AssetInfo::native("uusd").storage_namespace();  // should be "native:4:uusd"
+    /// ```
+    pub fn storage_namespace(&self) -> String {
+        match self {
+            AssetInfo::Cw20(contract_addr) => {
+                format!("cw20:{}:{}", contract_addr.as_str().len(), contract_addr)
+            }
+            AssetInfo::Native(denom) => format!("native:{}:{}", denom.len(), denom),
+        }
+    }
+
+    /// Encode the asset info as compact binary, e.g. for use in low-level storage keys
+    ///
+    /// The encoding is a one-byte type tag, `0` for native coins and `1` for CW20 tokens, followed
+    /// by the raw bytes of the denom or contract address. Unlike the `:`-delimited string form, it
+    /// is unambiguous whatever characters a denom contains, and byte-wise ordering of encodings
+    /// agrees with the ordering of asset infos. It can be decoded using [`AssetInfo::from_bytes`].
+    ///
+    /// NOTE: The encoding of one asset may be a prefix of another's, e.g. `uusd` and `uusdc`; when
+    /// combining it with other data into a single key, length-prefix it or put it last.
+    ///
+    /// ```rust
+    /// use cw_asset::AssetInfo;
+    ///
+    /// let bytes = AssetInfo::native("uusd").to_bytes();  // should be `b"\x00uusd"`
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (tag, value) = match self {
+            AssetInfo::Native(denom) => (NATIVE_BYTES_TAG, denom.as_bytes()),
+            AssetInfo::Cw20(contract_addr) => (CW20_BYTES_TAG, contract_addr.as_str().as_bytes()),
+        };
+        let mut bytes = Vec::with_capacity(1 + value.len());
+        bytes.push(tag);
+        bytes.extend_from_slice(value);
+        bytes
+    }
+
+    /// Decode an **asset info** instance from the binary encoding produced by
+    /// [`AssetInfo::to_bytes`]
+    ///
+    /// NOTE: CW20 contract addresses are not validated, as the encoding is meant to be read back
+    /// from storage written by the contract itself. Validate them if the bytes come from elsewhere.
+    ///
+    /// ```rust
+    /// use cw_asset::AssetInfo;
+    ///
+    /// let info = AssetInfo::from_bytes(b"\x00uusd").unwrap();  // should be `native:uusd`
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<AssetInfo, AssetError> {
+        let err = || AssetError::InvalidAssetInfoBytes {
+            bytes: bytes.to_vec(),
+        };
+
+        let (tag, value) = bytes.split_first().ok_or_else(err)?;
+        let value = String::from_utf8(value.to_vec()).map_err(|_| err())?;
+        match *tag {
+            NATIVE_BYTES_TAG => Ok(AssetInfo::Native(value)),
+            CW20_BYTES_TAG => Ok(AssetInfo::Cw20(Addr::unchecked(value))),
+            _ => Err(err()),
+        }
+    }
+
+    /// Return a copy of the asset info with the CW20 contract address cast to lowercase, the same
+    /// normalization [`AssetInfoUnchecked::check`] applies; native denoms are left unchanged
+    ///
+    /// This is useful for normalizing an already-checked asset info without validating it through
+    /// the API again.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Addr;
+    /// use cw_asset::AssetInfo;
+    ///
+    /// let info = AssetInfo::cw20(Addr::unchecked("TERRA1234ABCD")).lowercased();  // cw20:terra1234abcd
+    /// ```
+    pub fn lowercased(&self) -> AssetInfo {
+        match self {
+            // NOTE: We cast all contract addresses to lowercase, in order to prevent
+            // [a potential exploit](https://github.com/mars-protocol/cw-asset/issues/3)
+            AssetInfo::Cw20(contract_addr) => {
+                AssetInfo::Cw20(Addr::unchecked(contract_addr.as_str().to_lowercase()))
+            }
+            AssetInfo::Native(denom) => AssetInfo::Native(denom.clone()),
+        }
+    }
+
+    /// Return whether two asset infos represent the same asset, treating different representations
+    /// of the same IBC denom as equal
+    ///
+    /// IBC denoms are in the form `ibc/{hash}`, where the hash is conventionally in uppercase hex,
+    /// but user input may express it, or the prefix, in a different casing. This method compares
+    /// two IBC denoms by their hashes, ignoring the casing of both the prefix and the hash, as the
+    /// hex digits name the same hash in either casing; otherwise it is identical to `==`.
+    ///
+    /// ```rust
+    /// use cw_asset::AssetInfo;
+    ///
+    /// let a = AssetInfo::native("ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2");
+    /// let b = AssetInfo::native("ibc/27394fb092d2eccd56123c74f36e4c1f926001ceada9ca97ea622b25f41e5eb2");
+    ///
+    /// let same = a.same_asset(&b);  // should be true, while `a == b` is false
+    /// ```
+    pub fn same_asset(&self, other: &AssetInfo) -> bool {
+        match (self, other) {
+            (AssetInfo::Native(a), AssetInfo::Native(b)) => match (ibc_hash(a), ibc_hash(b)) {
+                (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                _ => a == b,
+            },
+            _ => self == other,
+        }
+    }
+
+    /// Return whether the asset is one of the given native denoms or CW20 contract addresses
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Addr;
+    /// use cw_asset::AssetInfo;
+    ///
+    /// fn is_stablecoin(info: &AssetInfo, ust_token: &Addr) -> bool {
+    ///     info.is_one_of(&["uusd", "ukrw"], &[ust_token])
+    /// }
+    /// ```
+    pub fn is_one_of(&self, natives: &[&str], cw20s: &[&Addr]) -> bool {
+        match self {
+            AssetInfo::Cw20(contract_addr) => cw20s.contains(&contract_addr),
+            AssetInfo::Native(denom) => natives.contains(&denom.as_str()),
+        }
+    }
+
+    /// Query an address' balance of the asset, treating a native denom that the bank module has
+    /// never seen as a zero balance
+    ///
+    /// On some chains, querying the balance of a denom that does not exist returns an error instead
+    /// of zero. This method swallows errors whose message contains `denom not found` (compared
+    /// case-insensitively) for native coins, returning zero instead. All other errors, as well as
+    /// any error querying a CW20 token, are returned as is.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+    /// use cw_asset::AssetInfo;
+    ///
+    /// fn query_ibc_balance(deps: Deps, account_addr: &Addr) -> StdResult<Uint128> {
+    ///     let info = AssetInfo::native("ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97");
+    ///     Ok(info.query_balance_safe(&deps.querier, account_addr)?)
+    /// }
+    /// ```
+    pub fn query_balance_safe<T: Into<String>>(
+        &self,
+        querier: &QuerierWrapper,
+        address: T,
+    ) -> Result<Uint128, AssetError> {
+        match self.query_balance(querier, address) {
+            Err(AssetError::Std(StdError::GenericErr {
+                msg,
+                ..
+            })) if matches!(self, AssetInfo::Native(_))
+                && msg.to_lowercase().contains("denom not found") =>
+            {
+                Ok(Uint128::zero())
+            }
+            result => result,
+        }
+    }
+
+    /// Query an address' balance of the asset by routing the query through a specified contract
+    ///
+    /// This is useful when balances are proxied by another contract, e.g. in cross-contract mocks.
+    /// The router must accept [`BalanceRouterQueryMsg::Balance`] and respond with a
+    /// [`cw20::BalanceResponse`].
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+    /// use cw_asset::AssetInfo;
+    ///
+    /// fn query_token_balance(deps: Deps, account_addr: &Addr, router_addr: &Addr) -> StdResult<Uint128> {
+    ///     let info = AssetInfo::cw20(Addr::unchecked("token_addr"));
+    ///     Ok(info.query_balance_via(&deps.querier, account_addr, router_addr)?)
+    /// }
+    /// ```
+    pub fn query_balance_via<T: Into<String>>(
+        &self,
+        querier: &QuerierWrapper,
+        address: T,
+        query_router: &Addr,
+    ) -> Result<Uint128, AssetError> {
+        let response: Cw20BalanceResponse = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: query_router.into(),
+            msg: to_binary(&BalanceRouterQueryMsg::Balance {
+                asset_info: self.clone().into(),
+                address: address.into(),
+            })?,
+        }))?;
+        Ok(response.balance)
+    }
+
+    /// Query an address' balance of the asset, returning the balance together with the asset info
+    /// and address that were queried
+    ///
+    /// This is useful for logging or debugging queries over multiple assets, where results may
+    /// otherwise get shuffled.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Deps, StdResult};
+    /// use cw_asset::{AssetInfo, BalanceQueryResult};
+    ///
+    /// fn query_uusd_balance(deps: Deps, account_addr: &Addr) -> StdResult<BalanceQueryResult> {
+    ///     let info = AssetInfo::native("uusd");
+    ///     Ok(info.query_balance_detailed(&deps.querier, account_addr)?)
+    /// }
+    /// ```
+    pub fn query_balance_detailed(
+        &self,
+        querier: &QuerierWrapper,
+        address: &Addr,
+    ) -> Result<BalanceQueryResult, AssetError> {
+        Ok(BalanceQueryResult {
+            info: self.clone(),
+            address: address.clone(),
+            balance: self.query_balance(querier, address)?,
+        })
+    }
+
+    /// Query the balances of multiple addresses, returned in the same order as the addresses
+    /// provided
+    ///
+    /// NOTE: Balances are currently queried one address at a time, as neither the bank module nor
+    /// the CW20 standard offers a batched query.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+    /// use cw_asset::AssetInfo;
+    ///
+    /// fn query_token_balances(
+    ///     deps: Deps,
+    ///     token_addr: &Addr,
+    ///     accounts: &[Addr],
+    /// ) -> StdResult<Vec<Uint128>> {
+    ///     let info = AssetInfo::cw20(token_addr.clone());
+    ///     Ok(info.query_balances(&deps.querier, accounts)?)
+    /// }
+    /// ```
+    pub fn query_balances<T: Into<String> + Clone>(
+        &self,
+        querier: &QuerierWrapper,
+        addresses: &[T],
+    ) -> Result<Vec<Uint128>, AssetError> {
+        addresses.iter().map(|address| self.query_balance(querier, address.clone())).collect()
+    }
+
+    /// Query the balances of multiple addresses, returning each as an **asset** instance in the
+    /// same order as the addresses provided
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Deps, StdResult};
+    /// use cw_asset::{Asset, AssetInfo};
+    ///
+    /// fn query_uusd_holdings(deps: Deps, accounts: &[Addr]) -> StdResult<Vec<Asset>> {
+    ///     let info = AssetInfo::native("uusd");
+    ///     Ok(info.query_balances_as_assets(&deps.querier, accounts)?)
+    /// }
+    /// ```
+    pub fn query_balances_as_assets<T: Into<String> + Clone>(
+        &self,
+        querier: &QuerierWrapper,
+        addresses: &[T],
+    ) -> Result<Vec<Asset>, AssetError> {
+        Ok(self
+            .query_balances(querier, addresses)?
+            .into_iter()
+            .map(|balance| Asset::new(self.clone(), balance))
+            .collect())
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -218,6 +954,13 @@ impl From<&astroport::asset::AssetInfo> for AssetInfo {
     }
 }
 
+#[cfg(feature = "astroport")]
+impl LegacyAssetInfo for astroport::asset::AssetInfo {
+    fn as_cw_asset_info(&self) -> AssetInfo {
+        self.into()
+    }
+}
+
 #[cfg(feature = "astroport")]
 impl std::cmp::PartialEq<AssetInfo> for astroport::asset::AssetInfo {
     fn eq(&self, other: &AssetInfo) -> bool {
@@ -279,27 +1022,183 @@ impl From<AssetInfo> for mars_core::asset::Asset {
     }
 }
 
-#[cfg(feature = "mars")]
-impl From<&AssetInfo> for mars_core::asset::Asset {
-    fn from(info: &AssetInfo) -> Self {
-        info.clone().into()
+#[cfg(feature = "mars")]
+impl From<&AssetInfo> for mars_core::asset::Asset {
+    fn from(info: &AssetInfo) -> Self {
+        info.clone().into()
+    }
+}
+
+#[cfg(feature = "mars")]
+impl From<mars_core::asset::Asset> for AssetInfoUnchecked {
+    fn from(legacy_info: mars_core::asset::Asset) -> Self {
+        match legacy_info {
+            mars_core::asset::Asset::Cw20 { contract_addr } => Self::Cw20(contract_addr),
+            mars_core::asset::Asset::Native { denom } => Self::Native(denom),
+        }
+    }
+}
+
+#[cfg(feature = "mars")]
+impl From<&mars_core::asset::Asset> for AssetInfoUnchecked {
+    fn from(legacy_info: &mars_core::asset::Asset) -> Self {
+        legacy_info.clone().into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Optional Feature: Stargate Support
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "stargate")]
+impl AssetInfo {
+    /// Query the asset's human-readable symbol
+    ///
+    /// - For CW20 tokens, the symbol is taken from the token contract's `token_info` query
+    /// - For native coins, the symbol is taken from the bank module's denom metadata, queried via
+    ///   the `/cosmos.bank.v1beta1.Query/DenomMetadata` stargate query; returns error if the chain
+    ///   has no metadata registered for the denom
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Deps, StdResult};
+    /// use cw_asset::AssetInfo;
+    ///
+    /// fn query_uusd_symbol(deps: Deps) -> StdResult<String> {
+    ///     Ok(AssetInfo::native("uusd").query_symbol(&deps.querier)?)
+    /// }
+    /// ```
+    pub fn query_symbol(&self, querier: &QuerierWrapper) -> Result<String, AssetError> {
+        use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+            QueryDenomMetadataRequest, QueryDenomMetadataResponse,
+        };
+        use cosmwasm_std::{to_vec, ContractResult, Empty, SystemResult};
+        use prost::Message;
+
+        match self {
+            AssetInfo::Cw20(contract_addr) => {
+                let response: TokenInfoResponse =
+                    querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                        contract_addr: contract_addr.into(),
+                        msg: to_binary(&Cw20QueryMsg::TokenInfo {})?,
+                    }))?;
+                Ok(response.symbol)
+            }
+            AssetInfo::Native(denom) => {
+                // stargate queries respond in protobuf rather than JSON, so they cannot be made
+                // via `QuerierWrapper::query`
+                let request: QueryRequest<Empty> = QueryRequest::Stargate {
+                    path: String::from("/cosmos.bank.v1beta1.Query/DenomMetadata"),
+                    data: QueryDenomMetadataRequest {
+                        denom: denom.clone(),
+                    }
+                    .encode_to_vec()
+                    .into(),
+                };
+                let raw = match querier.raw_query(&to_vec(&request)?) {
+                    SystemResult::Ok(ContractResult::Ok(raw)) => raw,
+                    SystemResult::Ok(ContractResult::Err(err)) => {
+                        return Err(AssetError::QuerierContractError {
+                            msg: err,
+                        })
+                    }
+                    SystemResult::Err(err) => {
+                        return Err(AssetError::QuerierSystemError {
+                            msg: err.to_string(),
+                        })
+                    }
+                };
+                let response = QueryDenomMetadataResponse::decode(raw.as_slice())
+                    .map_err(|err| StdError::parse_err("QueryDenomMetadataResponse", err))?;
+                response.metadata.map(|metadata| metadata.symbol).ok_or_else(|| {
+                    AssetError::DenomMetadataNotFound {
+                        denom: denom.clone(),
+                    }
+                })
+            }
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Optional Feature: cw-storage-plus Keys
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "storage-plus")]
+const NATIVE_KEY_PREFIX: &[u8] = b"native:";
+#[cfg(feature = "storage-plus")]
+const CW20_KEY_PREFIX: &[u8] = b"cw20:";
+
+/// Allows **asset info** instances to be used as keys of `cw-storage-plus` maps, e.g.
+/// `Map<&AssetInfo, Uint128>`
+///
+/// The key consists of two parts, the type prefix (`native:` or `cw20:`) and the denom or contract
+/// address, which concatenated make up the asset info's string form. As with other composite keys,
+/// the first part is length-prefixed when the two are joined.
+#[cfg(feature = "storage-plus")]
+impl<'a> cw_storage_plus::PrimaryKey<'a> for &'a AssetInfo {
+    type Prefix = ();
+    type SubPrefix = ();
+
+    fn key(&self) -> Vec<&[u8]> {
+        match self {
+            AssetInfo::Native(denom) => vec![NATIVE_KEY_PREFIX, denom.as_bytes()],
+            AssetInfo::Cw20(contract_addr) => {
+                vec![CW20_KEY_PREFIX, contract_addr.as_str().as_bytes()]
+            }
+        }
     }
 }
 
-#[cfg(feature = "mars")]
-impl From<mars_core::asset::Asset> for AssetInfoUnchecked {
-    fn from(legacy_info: mars_core::asset::Asset) -> Self {
-        match legacy_info {
-            mars_core::asset::Asset::Cw20 { contract_addr } => Self::Cw20(contract_addr),
-            mars_core::asset::Asset::Native { denom } => Self::Native(denom),
-        }
+#[cfg(feature = "storage-plus")]
+impl<'a> cw_storage_plus::Prefixer<'a> for &'a AssetInfo {
+    fn prefix(&self) -> Vec<&[u8]> {
+        cw_storage_plus::PrimaryKey::key(self)
     }
 }
 
-#[cfg(feature = "mars")]
-impl From<&mars_core::asset::Asset> for AssetInfoUnchecked {
-    fn from(legacy_info: &mars_core::asset::Asset) -> Self {
-        legacy_info.clone().into()
+#[cfg(feature = "storage-plus")]
+impl AssetInfo {
+    /// Parse an **asset info** instance from a joined `cw-storage-plus` key, e.g. as returned when
+    /// ranging over a `Map<&AssetInfo, T>`
+    ///
+    /// NOTE: The `cw-storage-plus` version compatible with this crate predates the `KeyDeserialize`
+    /// trait, so keys need to be parsed explicitly. As keys are only ever written from checked
+    /// asset infos, contract addresses are not validated again.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Order, StdResult, Storage, Uint128};
+    /// use cw_asset::AssetInfo;
+    /// use cw_storage_plus::Map;
+    ///
+    /// const BALANCES: Map<&AssetInfo, Uint128> = Map::new("balances");
+    ///
+    /// fn load_balances(storage: &dyn Storage) -> StdResult<Vec<(AssetInfo, Uint128)>> {
+    ///     BALANCES
+    ///         .range(storage, None, None, Order::Ascending)
+    ///         .map(|item| {
+    ///             let (key, balance) = item?;
+    ///             Ok((AssetInfo::from_storage_key(&key)?, balance))
+    ///         })
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn from_storage_key(key: &[u8]) -> Result<AssetInfo, AssetError> {
+        let err = || AssetError::InvalidStorageKey {
+            key: key.to_vec(),
+        };
+
+        if key.len() < 2 {
+            return Err(err());
+        }
+        let prefix_len = u16::from_be_bytes([key[0], key[1]]) as usize;
+        let prefix = key.get(2..2 + prefix_len).ok_or_else(err)?;
+        let value = String::from_utf8(key[2 + prefix_len..].to_vec()).map_err(|_| err())?;
+
+        match prefix {
+            NATIVE_KEY_PREFIX => Ok(AssetInfo::Native(value)),
+            CW20_KEY_PREFIX => Ok(AssetInfo::Cw20(Addr::unchecked(value))),
+            _ => Err(err()),
+        }
     }
 }
 
@@ -323,6 +1222,53 @@ mod test {
         assert_eq!(info, AssetInfo::Native(String::from("uusd")));
     }
 
+    #[test]
+    fn accessing_fields() {
+        let native = AssetInfo::native("uusd");
+        let cw20 = AssetInfo::cw20(Addr::unchecked("mock_token"));
+
+        assert_eq!(native.denom(), Some(&String::from("uusd")));
+        assert_eq!(cw20.denom(), None);
+        assert_eq!(native.cw20_address(), None);
+        assert_eq!(cw20.cw20_address(), Some(&Addr::unchecked("mock_token")));
+
+        let unchecked = AssetInfoUnchecked::native("uusd");
+        assert_eq!(unchecked.denom(), Some(&String::from("uusd")));
+        assert_eq!(AssetInfoUnchecked::cw20("mock_token").denom(), None);
+    }
+
+    #[test]
+    fn creating_assets_with_amount() {
+        let info = AssetInfo::cw20(Addr::unchecked("mock_token"));
+        let asset = info.with_amount(12345u128);
+        assert_eq!(asset.info, info);
+        assert_eq!(asset.amount, Uint128::new(12345));
+
+        let info = AssetInfo::native("uusd");
+        assert_eq!(info.with_amount(Uint128::new(69420)), Asset::native("uusd", 69420u128));
+    }
+
+    #[test]
+    fn creating_instances_from_pathological_input() {
+        let long = "u".repeat(100_000);
+        for input in ["", ":", "native:", "\u{0}", "💸💸💸", long.as_str()].iter() {
+            let native = AssetInfoUnchecked::native(*input);
+            assert_eq!(native, AssetInfoUnchecked::Native(input.to_string()));
+            let cw20 = AssetInfoUnchecked::cw20(*input);
+            assert_eq!(cw20, AssetInfoUnchecked::Cw20(input.to_string()));
+        }
+
+        let uusd = AssetInfoUnchecked::native("uusd");
+        assert_eq!(AssetInfoUnchecked::native_lossy(" uusd\n"), uusd);
+        assert_eq!(AssetInfoUnchecked::native_lossy("u\u{0}u\tsd"), uusd);
+        assert_eq!(AssetInfoUnchecked::native_lossy(""), AssetInfoUnchecked::native(""));
+        assert_eq!(AssetInfoUnchecked::native_lossy("\u{7f} "), AssetInfoUnchecked::native(""));
+
+        // construction succeeds, but validation may still reject
+        let api = MockApi::default();
+        assert!(AssetInfoUnchecked::cw20("").check(&api, None).is_err());
+    }
+
     #[test]
     fn comparing() {
         let uluna = AssetInfo::native("uluna");
@@ -337,18 +1283,149 @@ mod test {
         assert_eq!(astro == astro.clone(), true);
     }
 
+    #[test]
+    fn ordering() {
+        let mut infos = vec![
+            AssetInfo::cw20(Addr::unchecked("mars_token")),
+            AssetInfo::native("uusd"),
+            AssetInfo::cw20(Addr::unchecked("astro_token")),
+            AssetInfo::native("uluna"),
+        ];
+        infos.sort();
+        assert_eq!(
+            infos,
+            vec![
+                AssetInfo::native("uluna"),
+                AssetInfo::native("uusd"),
+                AssetInfo::cw20(Addr::unchecked("astro_token")),
+                AssetInfo::cw20(Addr::unchecked("mars_token")),
+            ]
+        );
+
+        let mut unchecked =
+            vec![AssetInfoUnchecked::cw20("astro_token"), AssetInfoUnchecked::native("uusd")];
+        unchecked.sort();
+        assert_eq!(unchecked[0], AssetInfoUnchecked::native("uusd"));
+    }
+
+    #[test]
+    fn hashing() {
+        use std::collections::HashSet;
+
+        let infos: HashSet<AssetInfo> = vec![
+            AssetInfo::native("uusd"),
+            AssetInfo::cw20(Addr::unchecked("astro_token")),
+            AssetInfo::native("uusd"),
+            AssetInfo::cw20(Addr::unchecked("astro_token")),
+            AssetInfo::native("uluna"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(infos.len(), 3);
+        assert!(infos.contains(&AssetInfo::native("uluna")));
+    }
+
+    #[test]
+    fn comparing_same_asset() {
+        let ibc_upper = AssetInfo::native("ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97");
+        let ibc_lower = AssetInfo::native("ibc/27394fb092d2eccd56123c74f36e4c1f926001ceada9ca97");
+        let ibc_prefix = AssetInfo::native("IBC/27394fb092d2eccd56123c74f36e4c1f926001ceada9ca97");
+        let ibc_other = AssetInfo::native("ibc/14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5");
+        let uusd = AssetInfo::native("uusd");
+        let uusd_upper = AssetInfo::native("UUSD");
+        let astro = AssetInfo::cw20(Addr::unchecked("astro_token"));
+
+        assert_eq!(ibc_upper.same_asset(&ibc_lower), true);
+        assert_eq!(ibc_lower.same_asset(&ibc_upper), true);
+        assert_eq!(ibc_prefix.same_asset(&ibc_upper), true);
+        assert_eq!(ibc_upper.same_asset(&ibc_other), false);
+        assert_eq!(ibc_upper.same_asset(&uusd), false);
+        assert_eq!(uusd.same_asset(&uusd.clone()), true);
+        assert_eq!(uusd.same_asset(&uusd_upper), false);
+        assert_eq!(astro.same_asset(&astro.clone()), true);
+        assert_eq!(astro.same_asset(&uusd), false);
+    }
+
+    #[test]
+    fn checking_membership() {
+        let astro_token = Addr::unchecked("astro_token");
+        let mars_token = Addr::unchecked("mars_token");
+
+        let uusd = AssetInfo::native("uusd");
+        assert_eq!(uusd.is_one_of(&["uluna", "uusd"], &[]), true);
+        assert_eq!(uusd.is_one_of(&["uluna"], &[&astro_token]), false);
+
+        let astro = AssetInfo::cw20(astro_token.clone());
+        assert_eq!(astro.is_one_of(&[], &[&mars_token, &astro_token]), true);
+        assert_eq!(astro.is_one_of(&["astro_token"], &[&mars_token]), false);
+    }
+
+    struct StubLegacyAssetInfo {
+        is_token: bool,
+        id: String,
+    }
+
+    impl LegacyAssetInfo for StubLegacyAssetInfo {
+        fn as_cw_asset_info(&self) -> AssetInfo {
+            if self.is_token {
+                AssetInfo::cw20(Addr::unchecked(&self.id))
+            } else {
+                AssetInfo::native(&self.id)
+            }
+        }
+    }
+
+    #[test]
+    fn comparing_legacy() {
+        let legacy_uusd = StubLegacyAssetInfo {
+            is_token: false,
+            id: String::from("uusd"),
+        };
+        let legacy_astro = StubLegacyAssetInfo {
+            is_token: true,
+            id: String::from("astro_token"),
+        };
+
+        let uusd = AssetInfo::native("uusd");
+        let astro = AssetInfo::cw20(Addr::unchecked("astro_token"));
+
+        assert_eq!(uusd.matches_legacy(&legacy_uusd), true);
+        assert_eq!(uusd.matches_legacy(&legacy_astro), false);
+        assert_eq!(astro.matches_legacy(&legacy_uusd), false);
+        assert_eq!(astro.matches_legacy(&legacy_astro), true);
+    }
+
+    #[test]
+    fn finding_canonical() {
+        let atom = AssetInfo::native("uatom");
+        let ibc_atom = AssetInfo::native("ibc/atom");
+        let uusd = AssetInfo::native("uusd");
+
+        let mut map = EquivalenceMap::new();
+        map.insert(ibc_atom.clone(), uusd.clone());
+        map.insert(ibc_atom.clone(), atom.clone());
+
+        assert_eq!(map.canonical(&ibc_atom), &atom);
+        assert_eq!(map.canonical(&atom), &atom);
+        assert_eq!(map.canonical(&uusd), &uusd);
+    }
+
     #[test]
     fn from_string() {
         let s = "native:uusd:12345";
         assert_eq!(
             AssetInfoUnchecked::from_str(s), 
-            Err(StdError::generic_err("invalid asset info format `native:uusd:12345`; must be in format `native:{denom}` or `cw20:{contract_addr}`")),
+            Err(AssetError::InvalidAssetInfoFormat {
+                received: String::from("native:uusd:12345"),
+            }),
         );
 
         let s = "cw721:galactic_punk";
         assert_eq!(
             AssetInfoUnchecked::from_str(s),
-            Err(StdError::generic_err("invalid asset type `cw721`; must be `native` or `cw20`")),
+            Err(AssetError::InvalidAssetType {
+                ty: String::from("cw721"),
+            }),
         );
 
         let s = "native:uusd";
@@ -371,6 +1448,135 @@ mod test {
 
         let info = AssetInfo::cw20(Addr::unchecked("mock_token"));
         assert_eq!(info.to_string(), String::from("cw20:mock_token"));
+
+        let info = AssetInfoUnchecked::native("uusd");
+        assert_eq!(info.to_string(), String::from("native:uusd"));
+
+        let info = AssetInfoUnchecked::cw20("MOCK_TOKEN");
+        assert_eq!(info.to_string(), String::from("cw20:MOCK_TOKEN"));
+        assert_eq!(AssetInfoUnchecked::from_str(&info.to_string()), Ok(info));
+    }
+
+    #[test]
+    fn classifying() {
+        let mars_token = Addr::unchecked("mars_token");
+        let lp_tokens = vec![Addr::unchecked("lp_token_1"), Addr::unchecked("lp_token_2")];
+
+        let mut classifier = AssetClassifier::new();
+        classifier
+            .register(AssetClass::Stable, |info| info.is_one_of(&["uusd", "ukrw"], &[]))
+            .register(AssetClass::Lp, move |info| match info {
+                AssetInfo::Native(denom) => denom.starts_with("gamm/pool/"),
+                AssetInfo::Cw20(contract_addr) => lp_tokens.contains(contract_addr),
+            })
+            .register(AssetClass::Governance, move |info| info.is_one_of(&[], &[&mars_token]));
+
+        let classify = |info: AssetInfo| info.classify(&classifier);
+        assert_eq!(classify(AssetInfo::native("uusd")), AssetClass::Stable);
+        assert_eq!(classify(AssetInfo::native("gamm/pool/1")), AssetClass::Lp);
+        assert_eq!(classify(AssetInfo::cw20(Addr::unchecked("lp_token_2"))), AssetClass::Lp);
+        assert_eq!(classify(AssetInfo::cw20(Addr::unchecked("mars_token"))), AssetClass::Governance);
+        assert_eq!(classify(AssetInfo::native("uluna")), AssetClass::Other);
+        assert_eq!(classify(AssetInfo::cw20(Addr::unchecked("astro_token"))), AssetClass::Other);
+    }
+
+    #[test]
+    fn adding_meta() {
+        let meta = TokenMeta::from(TokenInfoResponse {
+            name: String::from("Mars Token"),
+            symbol: String::from("MARS"),
+            decimals: 6,
+            total_supply: Uint128::new(1000000),
+        });
+        assert_eq!(
+            meta,
+            TokenMeta {
+                symbol: String::from("MARS"),
+                decimals: 6,
+            }
+        );
+
+        let info = AssetInfo::cw20(Addr::unchecked("mars_token")).with_meta(meta);
+        assert_eq!(info.to_string(), String::from("MARS (cw20:mars_token)"));
+
+        let other = AssetInfo::cw20(Addr::unchecked("mars_token")).with_meta(TokenMeta {
+            symbol: String::from("xMARS"),
+            decimals: 18,
+        });
+        assert_eq!(info == other, true);
+
+        let other = AssetInfo::cw20(Addr::unchecked("astro_token")).with_meta(info.meta.clone());
+        assert_eq!(info == other, false);
+    }
+
+    #[test]
+    fn storage_namespaces() {
+        let infos = vec![
+            AssetInfo::native("uusd"),
+            AssetInfo::native("uusd:1"),
+            AssetInfo::native("4:uusd"),
+            AssetInfo::native("native:4:uusd"),
+            AssetInfo::native(""),
+            AssetInfo::native("ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97"),
+            AssetInfo::cw20(Addr::unchecked("uusd")),
+            AssetInfo::cw20(Addr::unchecked("mock_token")),
+        ];
+
+        assert_eq!(infos[0].storage_namespace(), String::from("native:4:uusd"));
+        assert_eq!(infos[6].storage_namespace(), String::from("cw20:4:uusd"));
+
+        let namespaces: Vec<String> = infos.iter().map(|info| info.storage_namespace()).collect();
+        for (i, a) in namespaces.iter().enumerate() {
+            assert_eq!(
+                AssetInfoUnchecked::from_storage_namespace(a).unwrap(),
+                AssetInfoUnchecked::from(infos[i].clone()),
+            );
+            for (j, b) in namespaces.iter().enumerate() {
+                assert_eq!(i != j && b.starts_with(a.as_str()), false);
+            }
+        }
+
+        for s in ["native:5:uusd", "native:uusd", "cw721:4:punk", "native:x:uusd", "uusd"] {
+            assert_eq!(
+                AssetInfoUnchecked::from_storage_namespace(s),
+                Err(AssetError::InvalidStorageNamespace {
+                    namespace: String::from(s),
+                }),
+            );
+        }
+    }
+
+    #[test]
+    fn encoding_bytes() {
+        let infos = vec![
+            AssetInfo::native("uusd"),
+            AssetInfo::native("uusdc"),
+            AssetInfo::native("factory/addr/sub:denom"),
+            AssetInfo::native("native:uusd"),
+            AssetInfo::native(""),
+            AssetInfo::cw20(Addr::unchecked("uusd")),
+            AssetInfo::cw20(Addr::unchecked("mock_token")),
+        ];
+
+        assert_eq!(infos[0].to_bytes(), b"\x00uusd".to_vec());
+        assert_eq!(infos[5].to_bytes(), b"\x01uusd".to_vec());
+
+        for (i, a) in infos.iter().enumerate() {
+            assert_eq!(AssetInfo::from_bytes(&a.to_bytes()), Ok(a.clone()));
+            for (j, b) in infos.iter().enumerate() {
+                assert_eq!(a.to_bytes() == b.to_bytes(), i == j);
+                assert_eq!(a.to_bytes().cmp(&b.to_bytes()), a.cmp(b));
+            }
+        }
+
+        for bytes in [&b""[..], b"\x02uusd", b"\x00\xff"] {
+            assert_eq!(
+                AssetInfo::from_bytes(bytes),
+                Err(AssetError::InvalidAssetInfoBytes {
+                    bytes: bytes.to_vec(),
+                }),
+            );
+        }
     }
 
     #[test]
@@ -388,7 +1594,101 @@ mod test {
         let unchecked = AssetInfoUnchecked::native("uatom");
         assert_eq!(
             unchecked.check(&api, Some(&["uusd", "uluna", "uosmo"])), 
-            Err(StdError::generic_err("invalid denom uatom; must be uusd|uluna|uosmo")),
+            Err(AssetError::DenomNotWhitelisted {
+                denom: String::from("uatom"),
+                whitelist: String::from("uusd|uluna|uosmo"),
+            }),
+        );
+    }
+
+    #[test]
+    fn checking_denom() {
+        let api = MockApi::default();
+
+        let valid = vec![
+            "uusd",
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+            "factory/osmo1abcdefg/sub.denom_1-a:b",
+        ];
+        for denom in valid {
+            let unchecked = AssetInfoUnchecked::native(denom);
+            assert_eq!(unchecked.check(&api, None), Ok(AssetInfo::native(denom)));
+        }
+
+        let too_long = format!("u{}", "a".repeat(128));
+        let invalid = vec!["", "uu", "1usd", "/usd", "u usd", "uusd!", "üusd", too_long.as_str()];
+        for denom in invalid {
+            let unchecked = AssetInfoUnchecked::native(denom);
+            assert_eq!(
+                unchecked.check(&api, None),
+                Err(AssetError::InvalidDenom {
+                    denom: String::from(denom),
+                }),
+            );
+        }
+    }
+
+    #[test]
+    fn checking_not_blocked() {
+        let api = MockApi::default();
+
+        let unchecked = AssetInfoUnchecked::native("uusd");
+        assert_eq!(
+            unchecked.check_not_blocked(&api, &["ustake", "uluna"]).unwrap(),
+            AssetInfo::native("uusd"),
+        );
+
+        let unchecked = AssetInfoUnchecked::native("ustake");
+        assert_eq!(
+            unchecked.check_not_blocked(&api, &["ustake", "uluna"]),
+            Err(AssetError::DenomBlocked {
+                denom: String::from("ustake"),
+            }),
+        );
+
+        let unchecked = AssetInfoUnchecked::cw20("mock_token");
+        assert_eq!(
+            unchecked.check_not_blocked(&api, &["ustake", "uluna"]).unwrap(),
+            AssetInfo::cw20(Addr::unchecked("mock_token")),
+        );
+    }
+
+    #[test]
+    fn checking_normalized() {
+        let api = MockApi::default();
+
+        let hash = "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+        let canonical = AssetInfo::native(format!("ibc/{}", hash));
+        for prefix in ["ibc/", "IBC/", "Ibc/", "iBc/"] {
+            let unchecked = AssetInfoUnchecked::native(format!("{}{}", prefix, hash));
+            assert_eq!(unchecked.check_normalized(&api, None).unwrap(), canonical);
+        }
+
+        // the hash is preserved as is; `same_asset` still ignores its casing
+        let mixed = "27394fb092d2eccd56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+        for prefix in ["ibc/", "IBC/", "Ibc/"] {
+            let unchecked = AssetInfoUnchecked::native(format!("{}{}", prefix, mixed));
+            let normalized = unchecked.check_normalized(&api, None).unwrap();
+            assert_eq!(normalized, AssetInfo::native(format!("ibc/{}", mixed)));
+            assert_eq!(normalized == canonical, false);
+            assert_eq!(normalized.same_asset(&canonical), true);
+        }
+
+        // non-IBC denoms and cw20 addresses are unaffected
+        let unchecked = AssetInfoUnchecked::native("uUSD");
+        assert_eq!(unchecked.check_normalized(&api, None).unwrap(), AssetInfo::native("uUSD"));
+        let unchecked = AssetInfoUnchecked::cw20("MOCK_TOKEN");
+        assert_eq!(
+            unchecked.check_normalized(&api, None).unwrap(),
+            AssetInfo::cw20(Addr::unchecked("mock_token")),
+        );
+
+        // the whitelist is matched against the normalized denom
+        let whitelist = format!("ibc/{}", hash);
+        let unchecked = AssetInfoUnchecked::native(format!("IBC/{}", hash));
+        assert_eq!(
+            unchecked.check_normalized(&api, Some(&[whitelist.as_str()])).unwrap(),
+            canonical,
         );
     }
 
@@ -402,6 +1702,18 @@ mod test {
         assert_eq!(unchecked.check(&api, None).unwrap(), checked);
     }
 
+    #[test]
+    fn lowercasing() {
+        let info = AssetInfo::cw20(Addr::unchecked("Terra1234ABcd"));
+        assert_eq!(info.lowercased(), AssetInfo::cw20(Addr::unchecked("terra1234abcd")));
+
+        let unchecked = AssetInfoUnchecked::cw20("Terra1234ABcd");
+        assert_eq!(info.lowercased(), unchecked.check(&MockApi::default(), None).unwrap());
+
+        let info = AssetInfo::native("uUSD");
+        assert_eq!(info.lowercased(), info);
+    }
+
     #[test]
     fn querying_balance() {
         let mut deps = mock_dependencies();
@@ -416,6 +1728,116 @@ mod test {
         let balance2 = info2.query_balance(&deps.as_ref().querier, "bob").unwrap();
         assert_eq!(balance2, Uint128::new(67890));
     }
+
+    #[test]
+    fn querying_balances() {
+        let mut deps = mock_dependencies();
+        deps.querier.set_cw20_balance("mock_token", "alice", 12345);
+        deps.querier.set_cw20_balance("mock_token", "bob", 67890);
+        deps.querier.set_cw20_balance("mock_token", "charlie", 0);
+
+        let info = AssetInfo::cw20(Addr::unchecked("mock_token"));
+        let balances = info
+            .query_balances(&deps.as_ref().querier, &["bob", "charlie", "alice"])
+            .unwrap();
+        assert_eq!(balances, vec![Uint128::new(67890), Uint128::zero(), Uint128::new(12345)]);
+
+        let balances = info.query_balances::<&str>(&deps.as_ref().querier, &[]).unwrap();
+        assert!(balances.is_empty());
+
+        // a failing query for any of the addresses fails the whole batch
+        assert!(info.query_balances(&deps.as_ref().querier, &["alice", "dave"]).is_err());
+    }
+
+    #[test]
+    fn querying_balances_as_assets() {
+        let mut deps = mock_dependencies();
+        deps.querier.set_cw20_balance("mock_token", "alice", 12345);
+        deps.querier.set_cw20_balance("mock_token", "bob", 67890);
+
+        let info = AssetInfo::cw20(Addr::unchecked("mock_token"));
+        let assets =
+            info.query_balances_as_assets(&deps.as_ref().querier, &["alice", "bob"]).unwrap();
+        assert_eq!(
+            assets,
+            vec![Asset::new(info.clone(), 12345u128), Asset::new(info, 67890u128)]
+        );
+    }
+
+    #[test]
+    fn querying_balance_safe() {
+        let mut deps = mock_dependencies();
+        deps.querier.set_base_balances("alice", &[Coin::new(12345, "uusd")]);
+        deps.querier.set_bank_query_error("ibc/unknown", "Denom not found: ibc/unknown");
+        deps.querier.set_bank_query_error("ukrw", "bank module is unavailable");
+
+        let info = AssetInfo::native("uusd");
+        let balance = info.query_balance_safe(&deps.as_ref().querier, "alice").unwrap();
+        assert_eq!(balance, Uint128::new(12345));
+
+        let info = AssetInfo::native("ibc/unknown");
+        assert!(info.query_balance(&deps.as_ref().querier, "alice").is_err());
+        let balance = info.query_balance_safe(&deps.as_ref().querier, "alice").unwrap();
+        assert_eq!(balance, Uint128::zero());
+
+        let info = AssetInfo::native("ukrw");
+        let err = info.query_balance_safe(&deps.as_ref().querier, "alice");
+        assert_eq!(
+            err,
+            Err(AssetError::Std(StdError::generic_err(
+                "Querier contract error: bank module is unavailable"
+            )))
+        );
+    }
+
+    #[test]
+    fn querying_balance_via_router() {
+        let mut deps = mock_dependencies();
+        deps.querier.set_base_balances("alice", &[Coin::new(12345, "uusd")]);
+        deps.querier.set_cw20_balance("mock_token", "bob", 67890);
+        deps.querier.set_balance_router("mock_router");
+
+        let router = Addr::unchecked("mock_router");
+
+        let info1 = AssetInfo::native("uusd");
+        let balance1 = info1.query_balance_via(&deps.as_ref().querier, "alice", &router).unwrap();
+        assert_eq!(balance1, Uint128::new(12345));
+
+        let info2 = AssetInfo::cw20(Addr::unchecked("mock_token"));
+        let balance2 = info2.query_balance_via(&deps.as_ref().querier, "bob", &router).unwrap();
+        assert_eq!(balance2, Uint128::new(67890));
+    }
+
+    #[test]
+    fn querying_balance_detailed() {
+        let mut deps = mock_dependencies();
+        deps.querier.set_base_balances("alice", &[Coin::new(12345, "uusd")]);
+        deps.querier.set_cw20_balance("mock_token", "bob", 67890);
+
+        let info1 = AssetInfo::native("uusd");
+        let alice = Addr::unchecked("alice");
+        let result1 = info1.query_balance_detailed(&deps.as_ref().querier, &alice).unwrap();
+        assert_eq!(
+            result1,
+            BalanceQueryResult {
+                info: info1,
+                address: alice,
+                balance: Uint128::new(12345),
+            }
+        );
+
+        let info2 = AssetInfo::cw20(Addr::unchecked("mock_token"));
+        let bob = Addr::unchecked("bob");
+        let result2 = info2.query_balance_detailed(&deps.as_ref().querier, &bob).unwrap();
+        assert_eq!(
+            result2,
+            BalanceQueryResult {
+                info: info2,
+                address: bob,
+                balance: Uint128::new(67890),
+            }
+        );
+    }
 }
 
 #[cfg(all(test, feature = "astroport"))]
@@ -504,4 +1926,81 @@ mod tests_mars {
         assert_eq!(legacy_info, mars_core::asset::Asset::from(&info));
         assert_eq!(legacy_info, mars_core::asset::Asset::from(info));
     }
-}
\ No newline at end of file
+}
+
+#[cfg(all(test, feature = "stargate"))]
+mod tests_stargate {
+    use super::super::testing::mock_dependencies;
+    use super::*;
+
+    #[test]
+    fn querying_symbol() {
+        let mut deps = mock_dependencies();
+        deps.querier.set_denom_symbol("uusd", "UST");
+        deps.querier.set_cw20_token_info(
+            "mock_token",
+            TokenInfoResponse {
+                name: String::from("Mock Token"),
+                symbol: String::from("MOCK"),
+                decimals: 6,
+                total_supply: Uint128::new(12345),
+            },
+        );
+
+        let info = AssetInfo::native("uusd");
+        assert_eq!(info.query_symbol(&deps.as_ref().querier), Ok(String::from("UST")));
+
+        let info = AssetInfo::cw20(Addr::unchecked("mock_token"));
+        assert_eq!(info.query_symbol(&deps.as_ref().querier), Ok(String::from("MOCK")));
+
+        let info = AssetInfo::native("uluna");
+        assert!(info.query_symbol(&deps.as_ref().querier).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "storage-plus"))]
+mod tests_storage_plus {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::Order;
+    use cw_storage_plus::{Map, PrimaryKey};
+
+    const BALANCES: Map<&AssetInfo, Uint128> = Map::new("balances");
+
+    #[test]
+    fn storing_in_map() {
+        let mut storage = MockStorage::default();
+        let uusd = AssetInfo::native("uusd");
+        let token = AssetInfo::cw20(Addr::unchecked("mock_token"));
+        let lookalike = AssetInfo::native("mock_token");
+
+        BALANCES.save(&mut storage, &uusd, &Uint128::new(69420)).unwrap();
+        BALANCES.save(&mut storage, &token, &Uint128::new(88888)).unwrap();
+
+        assert_eq!(BALANCES.load(&storage, &uusd).unwrap(), Uint128::new(69420));
+        assert_eq!(BALANCES.load(&storage, &token).unwrap(), Uint128::new(88888));
+        assert_eq!(BALANCES.may_load(&storage, &lookalike).unwrap(), None);
+
+        let entries: Vec<(AssetInfo, Uint128)> = BALANCES
+            .range(&storage, None, None, Order::Ascending)
+            .map(|item| {
+                let (key, balance) = item.unwrap();
+                (AssetInfo::from_storage_key(&key).unwrap(), balance)
+            })
+            .collect();
+        assert_eq!(entries, vec![(token, Uint128::new(88888)), (uusd, Uint128::new(69420))]);
+    }
+
+    #[test]
+    fn parsing_storage_key() {
+        let infos =
+            [AssetInfo::native("ibc/ABCD:1"), AssetInfo::cw20(Addr::unchecked("mock_token"))];
+        for info in infos.iter() {
+            assert_eq!(AssetInfo::from_storage_key(&info.joined_key()), Ok(info.clone()));
+        }
+
+        for key in [&b""[..], b"\x00", b"\x00\x09native:", b"\x00\x06erc20:token"] {
+            assert!(AssetInfo::from_storage_key(key).is_err());
+        }
+    }
+}
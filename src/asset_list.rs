@@ -1,20 +1,60 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::convert::{TryFrom, TryInto};
 use std::fmt;
+use std::iter::{FromIterator, Sum};
 use std::str::FromStr;
-#[cfg(feature = "legacy")]
-use std::convert::TryInto;
 
-use cosmwasm_std::{Addr, Api, Coin, CosmosMsg, StdError, StdResult};
+use cosmwasm_std::{
+    Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Decimal, Response, StdError, StdResult, Uint128,
+};
+
+use cw20::Cw20Coin;
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::asset::{Asset, AssetBase, AssetUnchecked};
-use super::asset_info::AssetInfo;
+use super::asset::{Asset, AssetBase, AssetLike, AssetUnchecked, RoundingMode};
+use super::asset_info::{AssetInfo, EquivalenceMap};
+use super::error::AssetError;
 
 /// Represents a list of fungible tokens, each with a known amount
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+///
+/// The list is (de)serialized as a bare JSON array of assets, the only accepted shape, e.g.
+///
+/// ```json
+/// [
+///   { "info": { "native": "uusd" }, "amount": "12345" },
+///   { "info": { "cw20": "token_addr" }, "amount": "67890" }
+/// ]
+/// ```
+///
+/// This is the same shape as a serialized `Vec<Asset>`, so data stored as a plain vector of assets
+/// can be loaded as an **asset list** without migration, and vice versa.
+///
+/// NOTE: A single-field tuple struct already (de)serializes as its inner vector, so the
+/// `#[serde(transparent)]` attribute below does not change the format; it only pins it down
+/// explicitly. No object form of the list exists or is accepted.
+///
+/// NOTE: Equality of lists is order-independent: two lists are equal if they contain the same
+/// assets, i.e. the same (info, amount) pairs, the same number of times, disregarding assets of
+/// zero amount. Entries of the same asset are _not_ merged before comparison. Two equal lists may
+/// still differ in their string and binary representations; see [`AssetList::sort`].
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+#[serde(transparent)]
 pub struct AssetListBase<T>(Vec<AssetBase<T>>);
 
+impl<T: Ord> PartialEq for AssetListBase<T> {
+    fn eq(&self, other: &Self) -> bool {
+        fn normalize<T: Ord>(list: &AssetListBase<T>) -> Vec<&AssetBase<T>> {
+            let mut assets: Vec<&AssetBase<T>> =
+                list.0.iter().filter(|asset| !asset.amount.is_zero()).collect();
+            assets.sort_by(|a, b| a.info.cmp(&b.info).then_with(|| a.amount.cmp(&b.amount)));
+            assets
+        }
+        normalize(self) == normalize(other)
+    }
+}
+
 #[allow(clippy::derivable_impls)] // clippy says `Default` can be derived here, but actually it can't
 impl<T> Default for AssetListBase<T> {
     fn default() -> Self {
@@ -27,18 +67,23 @@ pub type AssetListUnchecked = AssetListBase<String>;
 /// Represents an **asset list** instance containing only verified data; to be used in contract storage
 pub type AssetList = AssetListBase<Addr>;
 
+/// Parse an _unchecked_ **asset list** instance from the format produced by its `Display`
+/// implementation, i.e. assets joined by commas, e.g. `native:uusd:12345,cw20:token:67890`
+///
+/// Whitespace around each asset and trailing commas are tolerated; an empty string results in an
+/// empty list.
 impl FromStr for AssetListUnchecked {
-    type Err = StdError;
+    type Err = AssetError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() == 0 {
-            return Ok(Self(vec![]));
+        // tolerate whitespace around each segment, as well as trailing commas
+        let mut segments: Vec<&str> = s.split(",").map(|segment| segment.trim()).collect();
+        while segments.last() == Some(&"") {
+            segments.pop();
         }
 
         Ok(Self(
-            s
-                .split(",")
-                .collect::<Vec<&str>>()
+            segments
                 .iter()
                 .map(|s| AssetUnchecked::from_str(s))
                 .collect::<Result<Vec<AssetUnchecked>, Self::Err>>()?
@@ -70,17 +115,121 @@ impl AssetListUnchecked {
     ///     }
     /// }
     /// ```
-    pub fn check(&self, api: &dyn Api, optional_whitelist: Option<&[&str]>) -> StdResult<AssetList> {
+    pub fn check(
+        &self,
+        api: &dyn Api,
+        optional_whitelist: Option<&[&str]>,
+    ) -> Result<AssetList, AssetError> {
         Ok(AssetList::from(
             self.0
                 .iter()
                 .map(|asset| asset.check(api, optional_whitelist))
-                .collect::<StdResult<Vec<Asset>>>()?
+                .collect::<Result<Vec<Asset>, AssetError>>()?
         ))
     }
+
+    /// Similar to `check`, but additionally merge entries of the same asset into one, summing up
+    /// their amounts
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Api, StdResult};
+    /// use cw_asset::{AssetList, AssetListUnchecked};
+    ///
+    /// fn validate_and_merge(
+    ///     api: &dyn Api,
+    ///     list_unchecked: &AssetListUnchecked,
+    /// ) -> StdResult<AssetList> {
+    ///     Ok(list_unchecked.check_and_merge(api, None)?)
+    /// }
+    /// ```
+    pub fn check_and_merge(
+        &self,
+        api: &dyn Api,
+        optional_whitelist: Option<&[&str]>,
+    ) -> Result<AssetList, AssetError> {
+        AssetList::aggregate(self.check(api, optional_whitelist)?.to_vec())
+    }
+}
+
+/// Incrementally constructs an _unchecked_ **asset list**, validating all entries at the end
+///
+/// ```rust
+/// use cosmwasm_std::{Api, StdResult};
+/// use cw_asset::{AssetList, AssetListBuilder};
+///
+/// fn build_assets(api: &dyn Api) -> AssetList {
+///     let mut builder = AssetListBuilder::new();
+///     builder.push_native("uusd", 12345u128).push_cw20("token_addr", 67890u128);
+///
+///     match builder.finalize(api, Some(&["uusd", "uluna"])) {
+///         Ok(list) => list,
+///         Err(errors) => {
+///             for (index, err) in errors {
+///                 println!("entry {} is invalid! reason: {}", index, err);
+///             }
+///             AssetList::new()
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetListBuilder {
+    list: AssetListUnchecked,
+}
+
+impl AssetListBuilder {
+    /// Create a new, empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a native coin of given denom and amount
+    pub fn push_native<A: Into<String>, B: Into<Uint128>>(
+        &mut self,
+        denom: A,
+        amount: B,
+    ) -> &mut Self {
+        self.list.0.push(AssetUnchecked::native(denom, amount));
+        self
+    }
+
+    /// Append a CW20 token of given contract address and amount
+    pub fn push_cw20<A: Into<String>, B: Into<Uint128>>(
+        &mut self,
+        contract_addr: A,
+        amount: B,
+    ) -> &mut Self {
+        self.list.0.push(AssetUnchecked::cw20(contract_addr, amount));
+        self
+    }
+
+    /// Validate every entry in the same way as [`AssetListUnchecked::check`]
+    ///
+    /// Return the _checked_ **asset list** if all entries are valid; otherwise, return the index
+    /// and error of every invalid entry.
+    pub fn finalize(
+        &self,
+        api: &dyn Api,
+        optional_whitelist: Option<&[&str]>,
+    ) -> Result<AssetList, Vec<(usize, AssetError)>> {
+        let mut assets = vec![];
+        let mut errors = vec![];
+        for (index, asset) in self.list.0.iter().enumerate() {
+            match asset.check(api, optional_whitelist) {
+                Ok(asset) => assets.push(asset),
+                Err(err) => errors.push((index, err)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(AssetList::from(assets))
+        } else {
+            Err(errors)
+        }
+    }
 }
 
-impl fmt::Display for AssetList {
+impl<T: fmt::Display> fmt::Display for AssetListBase<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -115,6 +264,25 @@ impl<'a> IntoIterator for &'a AssetList {
     }
 }
 
+impl IntoIterator for AssetList {
+    type Item = Asset;
+    type IntoIter = std::vec::IntoIter<Asset>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// Gives read-only access to the underlying slice, e.g. `iter`, `first`, and `get`. Indexing by
+// range is taken over by the `Index<usize>` impls above, so use `get(1..)` or `deref()[1..]`
+impl std::ops::Deref for AssetList {
+    type Target = [Asset];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 impl From<Vec<Asset>> for AssetList {
     fn from(vec: Vec<Asset>) -> Self {
         Self(vec)
@@ -151,6 +319,38 @@ impl From<&[Coin]> for AssetList {
     }
 }
 
+impl TryFrom<AssetList> for Vec<Coin> {
+    type Error = AssetError;
+    fn try_from(list: AssetList) -> Result<Self, Self::Error> {
+        (&list).try_into()
+    }
+}
+
+impl TryFrom<&AssetList> for Vec<Coin> {
+    type Error = AssetError;
+    fn try_from(list: &AssetList) -> Result<Self, Self::Error> {
+        list.0.iter().map(Coin::try_from).collect()
+    }
+}
+
+/// Collect assets into a list, merging assets of the same kind the same way as `add` does
+///
+/// NOTE: Panics if merging overflows. In contracts, prefer [`AssetList::aggregate`] instead.
+impl FromIterator<Asset> for AssetList {
+    fn from_iter<I: IntoIterator<Item = Asset>>(iter: I) -> Self {
+        AssetList::aggregate(iter).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Sum assets into a list, merging assets of the same kind the same way as `add` does
+///
+/// NOTE: Panics if merging overflows. In contracts, prefer [`AssetList::aggregate`] instead.
+impl Sum<Asset> for AssetList {
+    fn sum<I: Iterator<Item = Asset>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
 impl AssetList {
     /// Create a new, empty asset list
     ///
@@ -164,6 +364,39 @@ impl AssetList {
         AssetListBase::default()
     }
 
+    /// Create an asset list from a vector of assets, sorted as by [`sort`](Self::sort)
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let list = AssetList::from_sorted(vec![
+    ///     Asset::native("uusd", 67890u128),
+    ///     Asset::native("uluna", 12345u128),
+    /// ]);  // should be `native:uluna:12345,native:uusd:67890`
+    /// ```
+    pub fn from_sorted(assets: Vec<Asset>) -> Self {
+        let mut list = Self(assets);
+        list.sort();
+        list
+    }
+
+    /// Create an asset list from a slice of coins, e.g. the funds attached to a message
+    ///
+    /// Unlike converting from an owned `Vec<Coin>`, this does not clone the whole vector up front;
+    /// only the denom of each coin is copied into the resulting assets.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::MessageInfo;
+    /// use cw_asset::AssetList;
+    ///
+    /// fn assets_received(info: &MessageInfo) -> AssetList {
+    ///     AssetList::from_coin_refs(&info.funds)
+    /// }
+    /// ```
+    pub fn from_coin_refs(coins: &[Coin]) -> Self {
+        Self(coins.iter().map(Asset::from).collect())
+    }
+
     /// Return a copy of the underlying vector
     ///
     /// ```rust
@@ -180,6 +413,79 @@ impl AssetList {
         self.0.clone()
     }
 
+    /// Return the native coins in the list, in the form the bank module expects funds to be in:
+    /// sorted by denom, with coins of the same denom merged and zero amounts skipped
+    ///
+    /// CW20 tokens in the list are ignored.
+    ///
+    /// Throws an error if merging coins of the same denom overflows, which cannot happen for lists
+    /// that are only modified through `add`, as those never contain duplicates.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{to_binary, Addr, CosmosMsg, StdResult, WasmMsg};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn execute_with_funds(list: &AssetList, contract_addr: &Addr) -> StdResult<CosmosMsg> {
+    ///     Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+    ///         contract_addr: contract_addr.to_string(),
+    ///         msg: to_binary(&"deposit")?,
+    ///         funds: list.to_coins()?,
+    ///     }))
+    /// }
+    /// ```
+    pub fn to_coins(&self) -> Result<Vec<Coin>, AssetError> {
+        merge_coins(self.0.iter().filter_map(|asset| match &asset.info {
+            AssetInfo::Native(denom) => Some((denom.as_str(), asset.amount)),
+            AssetInfo::Cw20(_) => None,
+        }))
+    }
+
+    /// Sort the assets in the list in place: native coins first, sorted by denom, followed by CW20
+    /// tokens, sorted by contract address; entries of the same asset are sorted by amount
+    ///
+    /// This makes the list's string and binary representations deterministic, regardless of the
+    /// order in which it was built.
+    ///
+    /// NOTE: Sortedness is not maintained by other methods. `From` conversions keep the order given,
+    /// and `add` appends assets not already in the list to the end. Sort again after modifying the
+    /// list if a deterministic order is needed.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let mut list = AssetList::from(vec![
+    ///     Asset::native("uusd", 67890u128),
+    ///     Asset::native("uluna", 12345u128),
+    /// ]);
+    ///
+    /// list.sort();  // should be `native:uluna:12345,native:uusd:67890`
+    /// ```
+    pub fn sort(&mut self) -> &mut Self {
+        self.0.sort_by(|a, b| a.info.cmp(&b.info).then_with(|| a.amount.cmp(&b.amount)));
+        self
+    }
+
+    /// Consume the asset list, returning an iterator that yields the assets in the same
+    /// deterministic order as `sort`: native coins first, sorted by denom, followed by CW20 tokens,
+    /// sorted by contract address; entries of the same asset are sorted by amount
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let list = AssetList::from(vec![
+    ///     Asset::native("uusd", 67890u128),
+    ///     Asset::native("uluna", 12345u128),
+    /// ]);
+    ///
+    /// for asset in list.into_sorted_iter() {
+    ///     println!("{}", asset);  // should print uluna first, then uusd
+    /// }
+    /// ```
+    pub fn into_sorted_iter(mut self) -> impl Iterator<Item = Asset> {
+        self.sort();
+        self.0.into_iter()
+    }
+
     /// Return length of the asset list
     ///
     /// ```rust
@@ -196,6 +502,159 @@ impl AssetList {
         self.0.len()
     }
 
+    /// Return whether the asset list contains no assets
+    ///
+    /// ```rust
+    /// use cw_asset::AssetList;
+    ///
+    /// let list = AssetList::new();
+    /// let is_empty = list.is_empty();  // should be true
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Return the number of distinct CW20 tokens in the list, i.e. the number of token contracts
+    /// that will be invoked when generating messages
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{StdError, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn assert_fan_out(list: &AssetList) -> StdResult<()> {
+    ///     if list.distinct_cw20_count() > 10 {
+    ///         return Err(StdError::generic_err("too many cw20 tokens"));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn distinct_cw20_count(&self) -> usize {
+        self.0
+            .iter()
+            .filter_map(|asset| match &asset.info {
+                AssetInfo::Cw20(contract_addr) => Some(contract_addr),
+                AssetInfo::Native(_) => None,
+            })
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Return the number of distinct native coin denoms in the list
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let list = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    /// ]);
+    ///
+    /// let count = list.distinct_native_count();  // should be two
+    /// ```
+    pub fn distinct_native_count(&self) -> usize {
+        self.0
+            .iter()
+            .filter_map(|asset| match &asset.info {
+                AssetInfo::Native(denom) => Some(denom),
+                AssetInfo::Cw20(_) => None,
+            })
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Return an iterator over mutable references to the assets in the list
+    ///
+    /// NOTE: This is intended for adjusting amounts in place. Mutating an asset's `info` may break
+    /// the list's invariant that each asset type appears at most once, so only amounts should be
+    /// changed.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let mut list = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    /// ]);
+    ///
+    /// for asset in list.iter_mut() {
+    ///     asset.amount = asset.amount.multiply_ratio(1u128, 2u128);
+    /// }
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Asset> {
+        self.0.iter_mut()
+    }
+
+    /// Assert that every asset in the list is a native coin; throw an error naming the first CW20
+    /// token found otherwise
+    ///
+    /// ```rust
+    /// use cosmwasm_std::StdResult;
+    /// use cw_asset::AssetList;
+    ///
+    /// fn handle_deposit(list: &AssetList) -> StdResult<()> {
+    ///     list.assert_all_native()?;
+    ///     // handle deposit...
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn assert_all_native(&self) -> Result<(), AssetError> {
+        match self.0.iter().find(|asset| !matches!(asset.info, AssetInfo::Native(_))) {
+            Some(asset) => Err(AssetError::UnexpectedCw20 {
+                asset: asset.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Assert that every asset in the list is a CW20 token; throw an error naming the first native
+    /// coin found otherwise
+    ///
+    /// ```rust
+    /// use cosmwasm_std::StdResult;
+    /// use cw_asset::AssetList;
+    ///
+    /// fn handle_deposit(list: &AssetList) -> StdResult<()> {
+    ///     list.assert_all_cw20()?;
+    ///     // handle deposit...
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn assert_all_cw20(&self) -> Result<(), AssetError> {
+        match self.0.iter().find(|asset| !matches!(asset.info, AssetInfo::Cw20(_))) {
+            Some(asset) => Err(AssetError::UnexpectedNative {
+                asset: asset.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Partition the list into two: the first consisting of native coins, the second of CW20
+    /// tokens, each preserving the original order
+    ///
+    /// This is useful when sending assets along with a message, where native coins are attached
+    /// as funds, while CW20 tokens need to be sent or approved with separate messages:
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, StdResult};
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// fn split_funds() -> StdResult<()> {
+    ///     let list = AssetList::from(vec![
+    ///         Asset::native("uusd", 12345u128),
+    ///         Asset::cw20(Addr::unchecked("token_addr"), 67890u128),
+    ///     ]);
+    ///
+    ///     let (natives, cw20s) = list.split_native_cw20();
+    ///     let funds = natives.to_coins()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn split_native_cw20(&self) -> (AssetList, AssetList) {
+        let (natives, cw20s): (Vec<Asset>, Vec<Asset>) =
+            self.0.iter().cloned().partition(|asset| matches!(asset.info, AssetInfo::Native(_)));
+        (Self(natives), Self(cw20s))
+    }
+
     /// Find an asset in the list that matches the provided asset info
     ///
     /// Return `Some(&asset)` if found, where `&asset` is a reference to the asset found; `None` if
@@ -220,6 +679,65 @@ impl AssetList {
         self.0.iter().find(|asset| asset.info == *info)
     }
 
+    /// Similar to `find`, but return a mutable reference, allowing the asset to be updated in place
+    ///
+    /// For positional access, use `get`, which is available via the list's underlying slice.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Uint128;
+    /// use cw_asset::{AssetInfo, AssetList};
+    ///
+    /// fn add_bonus(list: &mut AssetList, bonus: Uint128) {
+    ///     if let Some(asset) = list.get_mut(&AssetInfo::native("uusd")) {
+    ///         asset.amount = asset.amount + bonus;
+    ///     }
+    /// }
+    /// ```
+    pub fn get_mut(&mut self, info: &AssetInfo) -> Option<&mut Asset> {
+        self.0.iter_mut().find(|asset| asset.info == *info)
+    }
+
+    /// Return the amount of the asset in the list that matches the provided asset info, or zero if
+    /// not found
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{MessageInfo, Uint128};
+    /// use cw_asset::{AssetInfo, AssetList};
+    ///
+    /// fn uusd_received_amount(info: &MessageInfo) -> Uint128 {
+    ///     AssetList::from(&info.funds).amount_of(&AssetInfo::native("uusd"))
+    /// }
+    /// ```
+    pub fn amount_of(&self, info: &AssetInfo) -> Uint128 {
+        self.find(info).map(|asset| asset.amount).unwrap_or_else(Uint128::zero)
+    }
+
+    /// Return the asset infos in `universe` that are either not present in the list, or present
+    /// with a zero amount, preserving the order of `universe`
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{StdError, StdResult};
+    /// use cw_asset::{AssetInfo, AssetList};
+    ///
+    /// fn assert_complete_deposit(list: &AssetList, pool_assets: &[AssetInfo]) -> StdResult<()> {
+    ///     let missing = list.missing_from(pool_assets);
+    ///     if !missing.is_empty() {
+    ///         return Err(StdError::generic_err(format!("missing {} assets", missing.len())));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn missing_from(&self, universe: &[AssetInfo]) -> Vec<AssetInfo> {
+        universe
+            .iter()
+            .filter(|info| match self.find(info) {
+                Some(asset) => asset.amount.is_zero(),
+                None => true,
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Apply a mutation on each of the asset
     ///
     /// An example case where this is useful is to scale the amount of each asset in the list by a
@@ -240,6 +758,25 @@ impl AssetList {
         self
     }
 
+    /// Similar to `apply`, but leave the list unchanged, and return a new **asset list** instance
+    /// consisting of the transformed assets instead
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let list = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    /// ]);
+    ///
+    /// let list_halved = list.map(|a| {
+    ///     Asset::new(a.info.clone(), a.amount.multiply_ratio(1u128, 2u128))
+    /// });
+    /// ```
+    pub fn map<F: FnMut(&Asset) -> Asset>(&self, f: F) -> AssetList {
+        Self(self.0.iter().map(f).collect())
+    }
+
     /// Removes all assets in the list that has zero amount
     ///
     /// ```rust
@@ -259,6 +796,66 @@ impl AssetList {
         self
     }
 
+    /// Retain only the assets for which the predicate returns `Ok(true)`
+    ///
+    /// If the predicate returns an error for any asset, evaluation stops and the error is returned,
+    /// in which case the list is left unchanged.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::StdError;
+    /// use cw_asset::{Asset, AssetInfo, AssetList};
+    ///
+    /// let mut list = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    /// ]);
+    ///
+    /// // keep uusd, drop other native coins, and reject CW20 tokens
+    /// list.try_retain(|asset| match &asset.info {
+    ///     AssetInfo::Native(denom) => Ok(denom == "uusd"),
+    ///     AssetInfo::Cw20(_) => Err(StdError::generic_err("cw20 tokens are not accepted")),
+    /// }).unwrap();
+    ///
+    /// let len = list.len();  // should be one
+    /// ```
+    pub fn try_retain<F: Fn(&Asset) -> StdResult<bool>>(&mut self, f: F) -> Result<(), AssetError> {
+        let keep = self.0.iter().map(f).collect::<StdResult<Vec<bool>>>()?;
+        let mut keep = keep.into_iter();
+        self.0.retain(|_| keep.next().unwrap_or(false));
+        Ok(())
+    }
+
+    /// Remove assets whose info is the same as that of an earlier asset in the list, keeping the
+    /// first occurrence and preserving order
+    ///
+    /// Unlike `add`, the amounts of duplicates are not summed up, which is useful e.g. when the list
+    /// represents a priority-ordered configuration.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let mut list = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    ///     Asset::native("uluna", 11111u128),
+    /// ]);
+    ///
+    /// list.dedup_first();
+    /// let len = list.len();  // should be two, with uluna amount still being 12345
+    /// ```
+    pub fn dedup_first(&mut self) -> &mut Self {
+        let mut seen: Vec<AssetInfo> = vec![];
+        self.0.retain(|asset| {
+            if seen.contains(&asset.info) {
+                false
+            } else {
+                seen.push(asset.info.clone());
+                true
+            }
+        });
+        self
+    }
+
     /// Add a new asset to the list
     ///
     /// If asset of the same kind already exists in the list, then increment its amount; if not,
@@ -285,7 +882,7 @@ impl AssetList {
     ///     .unwrap()
     ///     .amount;  // should have increased to 23456
     /// ```
-    pub fn add(&mut self, asset_to_add: &Asset) -> StdResult<&mut Self> {
+    pub fn add(&mut self, asset_to_add: &Asset) -> Result<&mut Self, AssetError> {
         match self.0.iter_mut().find(|asset| asset.info == asset_to_add.info) {
             Some(asset) => {
                 asset.amount = asset.amount.checked_add(asset_to_add.amount)?;
@@ -321,20 +918,70 @@ impl AssetList {
     ///     .unwrap()
     ///     .amount;  // should have increased to 23456
     /// ```
-    pub fn add_many(&mut self, assets_to_add: &AssetList) -> StdResult<&mut Self> {
+    pub fn add_many(&mut self, assets_to_add: &AssetList) -> Result<&mut Self, AssetError> {
         for asset in &assets_to_add.0 {
             self.add(asset)?;
         }
         Ok(self)
     }
 
-    /// Deduct an asset from the list
+    /// Create an asset list by summing up a stream of assets, grouped by asset info
+    ///
+    /// Throws an error if the sum of any asset overflows. As with `add`, assets with zero amounts are
+    /// not included in the resulting list.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let list = AssetList::aggregate(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    ///     Asset::native("uluna", 11111u128),
+    /// ]).unwrap();
+    ///
+    /// let len = list.len();  // should be two, with uluna amount being 23456
+    /// ```
+    pub fn aggregate<I: IntoIterator<Item = Asset>>(assets: I) -> Result<Self, AssetError> {
+        let mut list = Self::new();
+        for asset in assets {
+            list.add(&asset)?;
+        }
+        Ok(list)
+    }
+
+    /// Collapse assets that are declared equivalent in the given map into their canonical
+    /// representative, summing up their amounts
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetInfo, AssetList, EquivalenceMap};
+    ///
+    /// let mut map = EquivalenceMap::new();
+    /// map.insert(AssetInfo::native("ibc/atom"), AssetInfo::native("uatom"));
+    ///
+    /// let mut list = AssetList::from(vec![
+    ///     Asset::native("uatom", 12345u128),
+    ///     Asset::native("ibc/atom", 11111u128),
+    /// ]);
+    ///
+    /// list.merge_equivalents(&map).unwrap();
+    /// let len = list.len();  // should be one, with uatom amount being 23456
+    /// ```
+    pub fn merge_equivalents(&mut self, map: &EquivalenceMap) -> Result<&mut Self, AssetError> {
+        let mut merged = AssetList::new();
+        for asset in &self.0 {
+            merged.add(&Asset::new(map.canonical(&asset.info).clone(), asset.amount))?;
+        }
+        *self = merged;
+        Ok(self)
+    }
+
+    /// Deduct an asset from the list
     ///
     /// The asset of the same kind and equal or greater amount must already exist in the list. If so,
-    /// deduct the amount from the asset; ifnot, throw an error.
+    /// deduct the amount from the asset; if not, throw an error.
     ///
-    /// NOTE: `purge` is automatically performed following the addition. Therefore, if an asset's
-    /// amount is reduced to zero, it will be removed from the list.
+    /// NOTE: `purge` is automatically performed following the deduction. Therefore, if an asset's
+    /// amount is reduced to exactly zero, it will be removed from the list.
     ///
     /// ```
     /// use cw_asset::{Asset, AssetInfo, AssetList};
@@ -354,15 +1001,15 @@ impl AssetList {
     ///
     /// let len = list.len();  // should be zero, as uluna is purged from the list
     /// ```
-    pub fn deduct(&mut self, asset_to_deduct: &Asset) -> StdResult<&mut Self> {
+    pub fn deduct(&mut self, asset_to_deduct: &Asset) -> Result<&mut Self, AssetError> {
         match self.0.iter_mut().find(|asset| asset.info == asset_to_deduct.info) {
             Some(asset) => {
                 asset.amount = asset.amount.checked_sub(asset_to_deduct.amount)?;
             }
             None => {
-                return Err(StdError::generic_err(
-                    format!("not found in asset list: {}", asset_to_deduct.info)
-                ));
+                return Err(AssetError::NotFoundInList {
+                    info: asset_to_deduct.info.to_string(),
+                });
             }
         }
         Ok(self.purge())
@@ -390,289 +1037,2153 @@ impl AssetList {
     ///
     /// let len = list.len();  // should be zero, as uusd is purged from the list
     /// ```
-    pub fn deduct_many(&mut self, assets_to_deduct: &AssetList) -> StdResult<&mut Self> {
+    pub fn deduct_many(&mut self, assets_to_deduct: &AssetList) -> Result<&mut Self, AssetError> {
         for asset in &assets_to_deduct.0 {
             self.deduct(asset)?;
         }
         Ok(self)
     }
 
-    /// Generate a transfer messages for every asset in the list
+    /// Reduce each asset in the list to at most the amount of the same asset in `caps`
+    ///
+    /// Assets without a corresponding cap are left untouched. Return the amounts that were clamped
+    /// off as a new asset list.
+    ///
+    /// NOTE: `purge` is automatically performed following the clamping, so assets capped at zero
+    /// are removed from the list.
     ///
     /// ```rust
-    /// use cosmwasm_std::{Addr, Response, StdResult};
-    /// use cw_asset::{AssetList};
+    /// use cw_asset::{Asset, AssetList};
     ///
-    /// fn transfer_assets(list: &AssetList, recipient_addr: &Addr) -> StdResult<Response> {
-    ///     let msgs = list.transfer_msgs(recipient_addr)?;
+    /// let mut list = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    /// ]);
     ///
-    ///     Ok(Response::new()
-    ///         .add_messages(msgs)
-    ///         .add_attribute("assets_sent", list.to_string()))
+    /// let excess = list.clamp(&AssetList::from(vec![
+    ///     Asset::native("uusd", 60000u128),
+    /// ]));
+    ///
+    /// // `list` should now contain 12345 uluna and 60000 uusd; `excess` should contain 7890 uusd
+    /// ```
+    pub fn clamp(&mut self, caps: &AssetList) -> AssetList {
+        let mut excess = AssetList::new();
+        for asset in self.0.iter_mut() {
+            if let Some(cap) = caps.find(&asset.info) {
+                if asset.amount > cap.amount {
+                    excess.0.push(Asset::new(asset.info.clone(), asset.amount - cap.amount));
+                    asset.amount = cap.amount;
+                }
+            }
+        }
+        self.purge();
+        excess
+    }
+
+    /// Return a new list containing the sum of this list and `other`, leaving both untouched
+    ///
+    /// This is the non-mutating counterpart of `add_many`.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let list = AssetList::from(vec![Asset::native("uluna", 12345u128)]);
+    /// let sum = list.plus(&AssetList::from(vec![Asset::native("uusd", 67890u128)])).unwrap();
+    ///
+    /// let len = sum.len();  // should be two, while `list` still has length one
+    /// ```
+    pub fn plus(&self, other: &AssetList) -> Result<AssetList, AssetError> {
+        let mut sum = self.clone();
+        sum.add_many(other)?;
+        Ok(sum)
+    }
+
+    /// Return a new list containing the difference of this list and `other`, leaving both untouched
+    ///
+    /// This is the non-mutating counterpart of `deduct_many`.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let list = AssetList::from(vec![Asset::native("uluna", 12345u128)]);
+    /// let diff = list.minus(&AssetList::from(vec![Asset::native("uluna", 2345u128)])).unwrap();
+    ///
+    /// // `diff` should contain 10000 uluna, while `list` still contains 12345 uluna
+    /// ```
+    pub fn minus(&self, other: &AssetList) -> Result<AssetList, AssetError> {
+        let mut diff = self.clone();
+        diff.deduct_many(other)?;
+        Ok(diff)
+    }
+
+    /// Remove a basket of assets from the list
+    ///
+    /// Every asset in the requested basket must exist in the list with equal or greater amount. If
+    /// so, deduct all of them; if not, throw an error, in which case the list is left unchanged.
+    ///
+    /// NOTE: `purge` is automatically performed following the removal. Therefore, if an asset's
+    /// amount is reduced to zero, it will be removed from the list.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetInfo, AssetList};
+    ///
+    /// let mut list = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    /// ]);
+    ///
+    /// // not affordable; the list is left unchanged
+    /// let res = list.take(&AssetList::from(vec![
+    ///     Asset::native("uluna", 2345u128),
+    ///     Asset::native("uusd", 99999u128),
+    /// ]));
+    ///
+    /// let len = list.len();  // should still be two
+    /// ```
+    pub fn take(&mut self, requested: &AssetList) -> Result<(), AssetError> {
+        let mut remaining = self.clone();
+        remaining.deduct_many(requested)?;
+        *self = remaining;
+        Ok(())
+    }
+
+    /// Compute the signed change of each asset's amount between this list and a newer snapshot of
+    /// it
+    ///
+    /// Return a vector of `(info, delta)` tuples, where a positive `delta` means the amount has
+    /// increased in `newer`, and a negative one means it has decreased. Assets whose amount did not
+    /// change are omitted. Throws an error if a delta does not fit in an `i128`.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetInfo, AssetList};
+    ///
+    /// let before = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    /// ]);
+    /// let after = AssetList::from(vec![
+    ///     Asset::native("uluna", 10000u128),
+    ///     Asset::native("uusd", 70000u128),
+    /// ]);
+    ///
+    /// let deltas = before.signed_diff(&after).unwrap();  // should be [(uluna, -2345), (uusd, 2110)]
+    /// ```
+    pub fn signed_diff(&self, newer: &AssetList) -> Result<Vec<(AssetInfo, i128)>, AssetError> {
+        let mut infos: Vec<&AssetInfo> = self.0.iter().map(|asset| &asset.info).collect();
+        for asset in &newer.0 {
+            if self.find(&asset.info).is_none() {
+                infos.push(&asset.info);
+            }
+        }
+
+        let mut deltas = vec![];
+        for info in infos {
+            let before = self.find(info).map(|asset| asset.amount.u128()).unwrap_or(0);
+            let after = newer.find(info).map(|asset| asset.amount.u128()).unwrap_or(0);
+            let delta = if after >= before {
+                i128::try_from(after - before)
+            } else {
+                i128::try_from(before - after).map(|delta| -delta)
+            }
+            .map_err(|_| AssetError::DeltaOverflow {
+                info: info.to_string(),
+            })?;
+            if delta != 0 {
+                deltas.push((info.clone(), delta));
+            }
+        }
+        Ok(deltas)
+    }
+
+    /// Compute the overlap of the kinds of assets in two lists, ignoring amounts, as the number of
+    /// asset infos present in both lists divided by the number of distinct asset infos in either
+    ///
+    /// Return zero if both lists are empty.
+    ///
+    /// ```rust
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// let list1 = AssetList::from(vec![
+    ///     Asset::native("uluna", 12345u128),
+    ///     Asset::native("uusd", 67890u128),
+    /// ]);
+    /// let list2 = AssetList::from(vec![
+    ///     Asset::native("uusd", 1u128),
+    ///     Asset::native("ukrw", 1u128),
+    /// ]);
+    ///
+    /// let overlap = list1.info_overlap(&list2);  // should be 1/3
+    /// ```
+    pub fn info_overlap(&self, other: &AssetList) -> Decimal {
+        let infos: HashSet<&AssetInfo> = self.0.iter().map(|asset| &asset.info).collect();
+        let other_infos: HashSet<&AssetInfo> = other.0.iter().map(|asset| &asset.info).collect();
+        let shared = infos.intersection(&other_infos).count();
+        let total = infos.union(&other_infos).count();
+        if total == 0 {
+            return Decimal::zero();
+        }
+        Decimal::from_ratio(shared as u128, total as u128)
+    }
+
+    /// Compute the weighted average price of the assets in the list, i.e. the list's total value
+    /// divided by the total amount of all assets
+    ///
+    /// Each asset's value is its amount multiplied by its price, rounded down. Prices are assumed to
+    /// be quoted per unit of each asset's smallest denomination, in a common quote asset, so that
+    /// amounts of different assets can be meaningfully added up.
+    ///
+    /// Throws an error if any asset in the list does not have a price, or if the total amount is
+    /// zero.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    ///
+    /// use cosmwasm_std::Decimal;
+    /// use cw_asset::{Asset, AssetInfo, AssetList};
+    ///
+    /// let list = AssetList::from(vec![
+    ///     Asset::native("uluna", 10000u128),
+    ///     Asset::native("uusd", 30000u128),
+    /// ]);
+    ///
+    /// let mut prices = BTreeMap::new();
+    /// prices.insert(AssetInfo::native("uluna"), Decimal::from_ratio(50u128, 1u128));
+    /// prices.insert(AssetInfo::native("uusd"), Decimal::one());
+    ///
+    /// let price = list.weighted_price(&prices).unwrap();  // should be 13.25
+    /// ```
+    pub fn weighted_price(
+        &self,
+        prices: &BTreeMap<AssetInfo, Decimal>,
+    ) -> Result<Decimal, AssetError> {
+        let mut total_value = Uint128::zero();
+        let mut total_amount = Uint128::zero();
+        for asset in &self.0 {
+            let price = prices
+                .get(&asset.info)
+                .ok_or_else(|| AssetError::PriceNotFound {
+                    info: asset.info.to_string(),
+                })?;
+            total_value = total_value.checked_add(asset.checked_mul(*price)?.amount)?;
+            total_amount = total_amount.checked_add(asset.amount)?;
+        }
+
+        if total_amount.is_zero() {
+            return Err(AssetError::ZeroTotalAmount);
+        }
+
+        Ok(Decimal::from_ratio(total_value, total_amount))
+    }
+
+    /// Compute the total value of all assets in the list, given the price of each asset
+    ///
+    /// Each asset's value is its amount multiplied by its price, rounded down; the multiplication
+    /// is carried out such that intermediate results do not overflow. Prices are assumed to be
+    /// quoted in a common quote asset, as in [`weighted_price`](Self::weighted_price).
+    ///
+    /// Throws an error if any asset in the list does not have a price, or if the total value does
+    /// not fit in a `Uint128`.
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use cosmwasm_std::Decimal;
+    /// use cw_asset::{Asset, AssetInfo, AssetList};
+    ///
+    /// let list = AssetList::from(vec![
+    ///     Asset::native("uluna", 10000u128),
+    ///     Asset::native("uusd", 30000u128),
+    /// ]);
+    ///
+    /// let mut prices = HashMap::new();
+    /// prices.insert(AssetInfo::native("uluna"), Decimal::from_ratio(50u128, 1u128));
+    /// prices.insert(AssetInfo::native("uusd"), Decimal::one());
+    ///
+    /// let value = list.total_value(&prices).unwrap();  // should be 530000
+    /// ```
+    pub fn total_value(&self, prices: &HashMap<AssetInfo, Decimal>) -> Result<Uint128, AssetError> {
+        let mut total_value = Uint128::zero();
+        for asset in &self.0 {
+            let price = prices
+                .get(&asset.info)
+                .ok_or_else(|| AssetError::PriceNotFound {
+                    info: asset.info.to_string(),
+                })?;
+            total_value = total_value.checked_add(asset.checked_mul(*price)?.amount)?;
+        }
+        Ok(total_value)
+    }
+
+    /// Multiply each asset in the list by its own ratio, rounded in the specified direction,
+    /// returning a new **asset list** instance
+    ///
+    /// Assets without a ratio are left unchanged. Throws an error if any of the multiplications
+    /// overflows.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    ///
+    /// use cosmwasm_std::{Decimal, StdResult};
+    /// use cw_asset::{AssetInfo, AssetList, RoundingMode};
+    ///
+    /// fn compute_fees(list: &AssetList) -> StdResult<AssetList> {
+    ///     let mut fee_rates = BTreeMap::new();
+    ///     fee_rates.insert(AssetInfo::native("uluna"), Decimal::permille(3));
+    ///     fee_rates.insert(AssetInfo::native("uusd"), Decimal::permille(1));
+    ///
+    ///     Ok(list.apply_ratios(&fee_rates, RoundingMode::Ceil)?)
     /// }
     /// ```
-    pub fn transfer_msgs<A: Into<String> + Clone>(&self, to: A) -> StdResult<Vec<CosmosMsg>> {
+    pub fn apply_ratios(
+        &self,
+        ratios: &BTreeMap<AssetInfo, Decimal>,
+        mode: RoundingMode,
+    ) -> Result<AssetList, AssetError> {
         self.0
             .iter()
-            .map(|asset| asset.transfer_msg(to.clone()))
-            .collect::<StdResult<Vec<CosmosMsg>>>()
+            .map(|asset| match ratios.get(&asset.info) {
+                Some(ratio) => asset.checked_mul_rounded(*ratio, mode),
+                None => Ok(asset.clone()),
+            })
+            .collect::<Result<Vec<Asset>, AssetError>>()
+            .map(Self)
     }
-}
 
-//--------------------------------------------------------------------------------------------------
-// Optional Feature: Astroport Legacy Support
-//--------------------------------------------------------------------------------------------------
+    /// Assert that the given funds, e.g. those attached to a message, cover every native coin in
+    /// the list
+    ///
+    /// Coins of the same denom are summed up on both sides first, so that funds are never counted
+    /// towards more than one coin in the list. Each denom in the list must then be matched by an
+    /// equal or greater amount in the funds. Throws an error listing all coins that are not
+    /// covered, sorted by denom. CW20 tokens in the list are ignored, as they cannot be attached as
+    /// funds and need to be handled separately.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{MessageInfo, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn assert_fees_paid(info: &MessageInfo, fees: &AssetList) -> StdResult<()> {
+    ///     Ok(fees.cover_from_funds(&info.funds)?)
+    /// }
+    /// ```
+    pub fn cover_from_funds(&self, funds: &[Coin]) -> Result<(), AssetError> {
+        let received = merge_coins(funds.iter().map(|coin| (coin.denom.as_str(), coin.amount)))?;
+        let unmet: Vec<String> = self
+            .to_coins()?
+            .iter()
+            .filter(|required| {
+                !received
+                    .iter()
+                    .any(|coin| coin.denom == required.denom && coin.amount >= required.amount)
+            })
+            .map(|required| Asset::from(required).to_string())
+            .collect();
+
+        if !unmet.is_empty() {
+            return Err(AssetError::InsufficientFunds {
+                uncovered: unmet.join(","),
+            });
+        }
+        Ok(())
+    }
 
-#[cfg(feature = "astroport")]
-impl AssetList {
-    /// Create an `AssetList` instance from an array of Astroport assets
-    /// 
-    /// This is useful for parsing the result of `astroport::pair::QueryMsg::PoolResponse`
-    pub fn from_legacy(legacy_list: &[astroport::asset::Asset]) -> Self {
-        Self(legacy_list.to_vec().iter().map(|asset| asset.into()).collect())
+    /// Assert that the given funds, e.g. those attached to a message, match the native coins in the
+    /// list exactly, and return the remaining CW20 tokens
+    ///
+    /// Funds are checked using [`assert_sent`](Self::assert_sent), i.e. they must contain exactly
+    /// the same amount of each denom as the native coins in the list, with coins of the same denom
+    /// summed up and zero amounts disregarded. The CW20 tokens returned, excluding any of zero
+    /// amount, are to be handled separately, e.g. drawn from the sender using `pull_from_msgs`.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{MessageInfo, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn handle_deposit(info: &MessageInfo, deposit: &AssetList) -> StdResult<AssetList> {
+    ///     let cw20s = deposit.check_funds_and_split(&info.funds)?;
+    ///     // draw the cw20 tokens from the sender...
+    ///     Ok(cw20s)
+    /// }
+    /// ```
+    pub fn check_funds_and_split(&self, funds: &[Coin]) -> Result<AssetList, AssetError> {
+        self.assert_sent(funds)?;
+
+        let (_, mut cw20s) = self.split_native_cw20();
+        cw20s.purge();
+        Ok(cw20s)
     }
 
-    /// Cast an asset list to a fixed length array of Astroport assets
-    /// 
-    /// This is useful when creating `astroport::pair::ExecuteMsg::ProvideLiquidity` message
-    /// 
-    /// NOTE: `self` must have exactly two element, or it cannot be cast into the fixed length array.
-    pub fn try_into_legacy(&self) -> StdResult<[astroport::asset::Asset; 2]> {
+    /// Assert that the given funds, e.g. those attached to a message, are exactly the native coins
+    /// in the list, no more and no less
+    ///
+    /// Amounts are compared per denom, with duplicate entries on either side summed up and zero
+    /// amounts disregarded. Throws an error listing every denom whose amounts differ. CW20 tokens
+    /// in the list are ignored, as they cannot be attached as funds.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{MessageInfo, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn handle_payment(info: &MessageInfo, price: &AssetList) -> StdResult<()> {
+    ///     price.assert_sent(&info.funds)?;
+    ///     // handle payment...
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn assert_sent(&self, funds: &[Coin]) -> Result<(), AssetError> {
+        let expected: BTreeMap<String, Uint128> =
+            self.to_coins()?.into_iter().map(|coin| (coin.denom, coin.amount)).collect();
+        let received: BTreeMap<String, Uint128> =
+            merge_coins(funds.iter().map(|coin| (coin.denom.as_str(), coin.amount)))?
+                .into_iter()
+                .map(|coin| (coin.denom, coin.amount))
+                .collect();
+
+        let denoms: BTreeSet<&String> = expected.keys().chain(received.keys()).collect();
+        let discrepancies: Vec<String> = denoms
+            .into_iter()
+            .filter_map(|denom| {
+                let expected_amount = expected.get(denom).copied().unwrap_or_else(Uint128::zero);
+                let received_amount = received.get(denom).copied().unwrap_or_else(Uint128::zero);
+                if expected_amount == received_amount {
+                    None
+                } else {
+                    Some(format!(
+                        "{}: expected {}, received {}",
+                        denom, expected_amount, received_amount
+                    ))
+                }
+            })
+            .collect();
+
+        if !discrepancies.is_empty() {
+            return Err(AssetError::SentFundsMismatch {
+                discrepancies: discrepancies.join("; "),
+            });
+        }
+        Ok(())
+    }
+
+    /// Map every asset in the list to a [`Cw20Coin`], e.g. to seed the initial balances of a new
+    /// CW20 token, using a caller-provided function to resolve the recipient of each asset
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Addr;
+    /// use cw20::Cw20Coin;
+    /// use cw_asset::{Asset, AssetList};
+    ///
+    /// fn initial_balances(holdings: &AssetList, treasury_addr: &Addr) -> Vec<Cw20Coin> {
+    ///     holdings.to_cw20_initial_balances_mapping(|_| treasury_addr.clone())
+    /// }
+    /// ```
+    pub fn to_cw20_initial_balances_mapping<F: Fn(&Asset) -> Addr>(
+        &self,
+        addr_for: F,
+    ) -> Vec<Cw20Coin> {
         self.0
             .iter()
-            .cloned()
-            .map(|asset| astroport::asset::Asset::from(asset))
-            .collect::<Vec<astroport::asset::Asset>>()
-            .try_into()
-            .map_err(|_| StdError::generic_err(format!("failed to map AssetList to legacy: {}", self)))
+            .map(|asset| Cw20Coin {
+                address: addr_for(asset).into(),
+                amount: asset.amount,
+            })
+            .collect()
     }
-}
 
-//--------------------------------------------------------------------------------------------------
-// Tests
-//--------------------------------------------------------------------------------------------------
+    /// Generate messages that send every asset in the list to a contract, each with the same binary
+    /// payload
+    ///
+    /// NOTE: Only works if the list contains CW20 tokens only. Returns error if any asset is a native
+    /// coin, as native coins do not have an equivalent method implemented.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{to_binary, Addr, Response, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn send_assets(list: &AssetList, contract_addr: &Addr) -> StdResult<Response> {
+    ///     let msgs = list.send_msgs(contract_addr, to_binary(&"distribute")?)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_messages(msgs)
+    ///         .add_attribute("assets_sent", list.to_string()))
+    /// }
+    /// ```
+    pub fn send_msgs<A: Into<String> + Clone>(
+        &self,
+        to: A,
+        msg: Binary,
+    ) -> Result<Vec<CosmosMsg>, AssetError> {
+        self.0
+            .iter()
+            .map(|asset| asset.send_msg(to.clone(), msg.clone()))
+            .collect::<Result<Vec<CosmosMsg>, AssetError>>()
+    }
 
-#[cfg(test)]
-mod test_helpers {
-    use super::super::asset::Asset;
-    use super::*;
+    /// Generate messages that draw every asset in the list from `owner` to `to`
+    ///
+    /// NOTE: Native coins cannot be pulled from an account; they must instead be attached as funds
+    /// to the message sent by the owner. Therefore, this only works if the list contains CW20
+    /// tokens only. Returns error listing all native coins in the list otherwise.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Response, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn draw_assets(list: &AssetList, user_addr: &Addr, contract_addr: &Addr) -> StdResult<Response> {
+    ///     let msgs = list.pull_from_msgs(user_addr, contract_addr)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_messages(msgs)
+    ///         .add_attribute("assets_drawn", list.to_string()))
+    /// }
+    /// ```
+    pub fn pull_from_msgs<A: Into<String> + Clone, B: Into<String> + Clone>(
+        &self,
+        owner: A,
+        to: B,
+    ) -> Result<Vec<CosmosMsg>, AssetError> {
+        let natives: Vec<String> = self
+            .0
+            .iter()
+            .filter(|asset| matches!(asset.info, AssetInfo::Native(_)))
+            .map(|asset| asset.to_string())
+            .collect();
+        if !natives.is_empty() {
+            return Err(AssetError::CannotPullNative {
+                assets: natives.join(","),
+            });
+        }
+
+        self.0
+            .iter()
+            .map(|asset| asset.transfer_from_msg(owner.clone(), to.clone()))
+            .collect::<Result<Vec<CosmosMsg>, AssetError>>()
+    }
+
+    /// Generate a minimal set of messages that transfer every asset in the list
+    ///
+    /// All native coins are sent in a single bank message, in the form given by `to_coins`, i.e.
+    /// merged by denom, sorted, and with zero amounts skipped; the bank message is omitted if there
+    /// is nothing to send. It is followed by one message for each CW20 token, sorted by contract
+    /// address. The messages are therefore deterministic regardless of the order of assets in the
+    /// list.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Response, StdResult};
+    /// use cw_asset::{AssetList};
+    ///
+    /// fn transfer_assets(list: &AssetList, recipient_addr: &Addr) -> StdResult<Response> {
+    ///     let msgs = list.transfer_msgs(recipient_addr)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_messages(msgs)
+    ///         .add_attribute("assets_sent", list.to_string()))
+    /// }
+    /// ```
+    pub fn transfer_msgs<A: Into<String> + Clone>(
+        &self,
+        to: A,
+    ) -> Result<Vec<CosmosMsg>, AssetError> {
+        let mut msgs = Vec::with_capacity(self.0.len());
+        self.append_transfer_msgs(to, &mut msgs)?;
+        Ok(msgs)
+    }
+
+    /// Generate messages that refund every asset in the list to a specified account, e.g. when a
+    /// deposit is rejected
+    ///
+    /// Messages are generated the same way as in `transfer_msgs`.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Response, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn refund_assets(list: &AssetList, sender_addr: &Addr) -> StdResult<Response> {
+    ///     let msgs = list.refund_msgs(sender_addr)?;
+    ///
+    ///     Ok(Response::new()
+    ///         .add_messages(msgs)
+    ///         .add_attribute("assets_refunded", list.to_string()))
+    /// }
+    /// ```
+    pub fn refund_msgs<A: Into<String> + Clone>(
+        &self,
+        to: A,
+    ) -> Result<Vec<CosmosMsg>, AssetError> {
+        self.transfer_msgs(to)
+    }
+
+    /// Generate a response that transfers every asset in the list to a specified account, and logs
+    /// the assets sent in an `assets_sent` attribute
+    ///
+    /// Messages are generated the same way as in `transfer_msgs`, i.e. all native coins are sent in
+    /// a single bank message.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Response, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn transfer_assets(list: &AssetList, recipient_addr: &Addr) -> StdResult<Response> {
+    ///     Ok(list.transfer_response(recipient_addr)?)
+    /// }
+    /// ```
+    pub fn transfer_response<A: Into<String> + Clone>(
+        &self,
+        to: A,
+    ) -> Result<Response, AssetError> {
+        Ok(Response::new()
+            .add_messages(self.transfer_msgs(to)?)
+            .add_attribute("assets_sent", self.to_string()))
+    }
+
+    /// Generate the same messages as `transfer_msgs`, and append them to the end of a given vector
+    ///
+    /// This avoids allocating an intermediate vector when combining the transfer messages with
+    /// other messages. If an error is thrown, the vector is left unchanged.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, CosmosMsg, Response, StdResult};
+    /// use cw_asset::AssetList;
+    ///
+    /// fn transfer_assets(
+    ///     list: &AssetList,
+    ///     recipient_addr: &Addr,
+    ///     mut msgs: Vec<CosmosMsg>,
+    /// ) -> StdResult<Response> {
+    ///     list.append_transfer_msgs(recipient_addr, &mut msgs)?;
+    ///
+    ///     Ok(Response::new().add_messages(msgs))
+    /// }
+    /// ```
+    pub fn append_transfer_msgs<A: Into<String> + Clone>(
+        &self,
+        to: A,
+        msgs: &mut Vec<CosmosMsg>,
+    ) -> Result<(), AssetError> {
+        // native coins are merged into one message anyway; sorting orders the CW20 transfers
+        let mut assets: Vec<&Asset> = self.0.iter().collect();
+        assets.sort_by(|a, b| a.info.cmp(&b.info));
+        msgs.extend(transfer_all(&assets, &to.into())?);
+        Ok(())
+    }
+}
+
+/// Generate a minimal set of messages that transfer every asset in a slice, dispatching through
+/// [`AssetLike`], so that it works with custom asset types as well
+///
+/// Assets for which `native_coin` returns a coin are merged by denom, and sent in a single bank
+/// message, in the same form as [`AssetList::to_coins`]; the bank message is omitted if there is
+/// nothing to send. It is followed by the message returned by `transfer_msg` of every other asset,
+/// in the order given.
+///
+/// ```rust
+/// use cosmwasm_std::{Addr, CosmosMsg, StdResult};
+/// use cw_asset::{transfer_all, Asset};
+///
+/// fn transfer_assets(assets: &[Asset], recipient_addr: &Addr) -> StdResult<Vec<CosmosMsg>> {
+///     Ok(transfer_all(assets, recipient_addr.as_str())?)
+/// }
+/// ```
+pub fn transfer_all<L: AssetLike>(assets: &[L], to: &str) -> Result<Vec<CosmosMsg>, AssetError> {
+    let mut coins = vec![];
+    let mut msgs = vec![];
+    for asset in assets {
+        match asset.native_coin() {
+            Some(coin) => coins.push(coin),
+            None => msgs.push(asset.transfer_msg(to)?),
+        }
+    }
+
+    let coins = merge_coins(coins.iter().map(|coin| (coin.denom.as_str(), coin.amount)))?;
+    if !coins.is_empty() {
+        msgs.insert(
+            0,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: String::from(to),
+                amount: coins,
+            }),
+        );
+    }
+    Ok(msgs)
+}
+
+/// Merge coins of the same denom and skip zero amounts, returning the coins sorted by denom;
+/// throws an error if merging overflows
+fn merge_coins<'a, I: IntoIterator<Item = (&'a str, Uint128)>>(
+    coins: I,
+) -> Result<Vec<Coin>, AssetError> {
+    let mut amounts: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for (denom, amount) in coins {
+        if !amount.is_zero() {
+            let total = amounts.entry(denom).or_insert_with(Uint128::zero);
+            *total = total.checked_add(amount)?;
+        }
+    }
+    Ok(amounts.into_iter().map(|(denom, amount)| Coin::new(amount.u128(), denom)).collect())
+}
+
+//--------------------------------------------------------------------------------------------------
+// Optional Feature: Astroport Legacy Support
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "astroport")]
+impl AssetList {
+    /// Create an `AssetList` instance from an array of Astroport assets
+    /// 
+    /// This is useful for parsing the result of `astroport::pair::QueryMsg::PoolResponse`
+    pub fn from_legacy(legacy_list: &[astroport::asset::Asset]) -> Self {
+        Self(legacy_list.to_vec().iter().map(|asset| asset.into()).collect())
+    }
+
+    /// Cast an asset list to a fixed length array of Astroport assets
+    /// 
+    /// This is useful when creating `astroport::pair::ExecuteMsg::ProvideLiquidity` message
+    /// 
+    /// NOTE: `self` must have exactly two element, or it cannot be cast into the fixed length array.
+    pub fn try_into_legacy(&self) -> Result<[astroport::asset::Asset; 2], AssetError> {
+        self.0
+            .iter()
+            .cloned()
+            .map(|asset| astroport::asset::Asset::from(asset))
+            .collect::<Vec<astroport::asset::Asset>>()
+            .try_into()
+            .map_err(|_| AssetError::LegacyCastFailed {
+                list: self.to_string(),
+            })
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Optional Feature: Stargate Support
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "stargate")]
+impl AssetList {
+    /// Cast the asset list into protobuf coins, sorted by denom as the SDK expects
+    ///
+    /// This is useful when building stargate messages that take repeated coins.
+    ///
+    /// NOTE: Only works if the list contains native coins only. Returns error if any asset is a
+    /// CW20 token.
+    pub fn to_proto_coins(
+        &self,
+    ) -> Result<Vec<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>, AssetError> {
+        let mut coins = self
+            .0
+            .iter()
+            .map(|asset| {
+                let coin = Coin::try_from(asset)?;
+                Ok(cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+                    denom: coin.denom,
+                    amount: coin.amount.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, AssetError>>()?;
+        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+        Ok(coins)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Optional Feature: Off-chain Tooling Support
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "std")]
+impl AssetList {
+    /// Convert the asset list into a [`serde_json::Value`]
+    ///
+    /// This is useful in off-chain scripts, where the list needs to be embedded in arbitrary JSON.
+    pub fn to_value(&self) -> Result<serde_json::Value, AssetError> {
+        serde_json::to_value(self)
+            .map_err(|err| StdError::serialize_err("AssetList", err).into())
+    }
+
+    /// Create an asset list from a [`serde_json::Value`]
+    ///
+    /// NOTE: Addresses contained in the value are not validated.
+    pub fn from_value(value: serde_json::Value) -> Result<Self, AssetError> {
+        serde_json::from_value(value).map_err(|err| StdError::parse_err("AssetList", err).into())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test_helpers {
+    use super::super::asset::Asset;
+    use super::*;
+
+    pub fn uluna() -> AssetInfo {
+        AssetInfo::native("uluna")
+    }
+
+    pub fn uusd() -> AssetInfo {
+        AssetInfo::native("uusd")
+    }
+
+    pub fn mock_token() -> AssetInfo {
+        AssetInfo::cw20(Addr::unchecked("mock_token"))
+    }
+
+    pub fn mock_list() -> AssetList {
+        AssetList::from(vec![
+            Asset::native("uusd", 69420u128), 
+            Asset::new(mock_token(), 88888u128),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::asset::{Asset, AssetUnchecked};
+    use super::test_helpers::{mock_list, mock_token, uluna, uusd};
+    use super::*;
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::{
+        attr, from_slice, to_binary, to_vec, BankMsg, Coin, CosmosMsg, Decimal, OverflowError,
+        OverflowOperation, Uint128, WasmMsg,
+    };
+    use cw20::Cw20ExecuteMsg;
+
+    #[test]
+    fn from_string() {
+        let s = "";
+        assert_eq!(AssetListUnchecked::from_str(s).unwrap(), AssetListBase::<String>(vec![]));
+
+        let s = "native:uusd:69420,cw20:mock_token";
+        assert_eq!(
+            AssetListUnchecked::from_str(s),
+            Err(AssetError::InvalidAssetFormat {
+                received: String::from("cw20:mock_token"),
+            }),
+        );
+
+        let s = "native:uusd:69420,cw721:galactic_punk:1";
+        assert_eq!(
+            AssetListUnchecked::from_str(s),
+            Err(AssetError::InvalidAssetType {
+                ty: String::from("cw721"),
+            }),
+        );
+
+        let s = "native:uusd:69420,cw20:mock_token:ngmi";
+        assert_eq!(
+            AssetListUnchecked::from_str(s),
+            Err(AssetError::InvalidAssetAmount {
+                amount: String::from("ngmi"),
+            }),
+        );
+
+        let s = "native:uusd:69420,cw20:mock_token:88888";
+        assert_eq!(AssetListUnchecked::from_str(s).unwrap(), AssetListUnchecked::from(mock_list()));
+
+        let s = "native:uusd:69420, cw20:mock_token:88888,";
+        assert_eq!(AssetListUnchecked::from_str(s).unwrap(), AssetListUnchecked::from(mock_list()));
+
+        let s = "  native:uusd:69420 ,\tcw20:mock_token:88888 , , ";
+        assert_eq!(AssetListUnchecked::from_str(s).unwrap(), AssetListUnchecked::from(mock_list()));
+
+        let s = "native:uusd:69420,,cw20:mock_token:88888";
+        assert!(AssetListUnchecked::from_str(s).is_err());
+    }
+
+    #[test]
+    fn serializing() {
+        let list = mock_list();
+        let json = r#"[{"info":{"native":"uusd"},"amount":"69420"},{"info":{"cw20":"mock_token"},"amount":"88888"}]"#;
+        assert_eq!(to_vec(&list).unwrap(), json.as_bytes());
+        assert_eq!(from_slice::<AssetList>(json.as_bytes()).unwrap(), list);
+
+        // data stored as a plain vector of assets can be loaded as an asset list, and vice versa
+        let vec_json = to_vec(&list.to_vec()).unwrap();
+        assert_eq!(from_slice::<AssetList>(&vec_json).unwrap(), list);
+        assert_eq!(from_slice::<Vec<Asset>>(json.as_bytes()).unwrap(), list.to_vec());
+
+        let empty = from_slice::<AssetList>(b"[]").unwrap();
+        assert_eq!(empty, AssetList::new());
+
+        // the bare array is the only accepted shape
+        assert!(from_slice::<AssetList>(br#"{"assets":[]}"#).is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        let list = mock_list();
+        assert_eq!(list.to_string(), String::from("native:uusd:69420,cw20:mock_token:88888"));
+
+        let list = AssetListUnchecked::from(mock_list());
+        assert_eq!(list.to_string(), String::from("native:uusd:69420,cw20:mock_token:88888"));
+
+        assert_eq!(AssetList::new().to_string(), String::new());
+    }
+
+    #[test]
+    fn parsing_display_round_trip() {
+        for list in vec![mock_list(), AssetList::new()] {
+            let parsed = AssetListUnchecked::from_str(&list.to_string()).unwrap();
+            assert_eq!(parsed, AssetListUnchecked::from(list.clone()));
+            assert_eq!(parsed.check(&MockApi::default(), None).unwrap(), list);
+        }
+    }
+
+    #[test]
+    fn indexing() {
+        let list = mock_list();
+        let vec = list.to_vec();
+        assert_eq!(list[0], vec[0]);
+        assert_eq!(list[1], vec[1]);
+    }
+
+    #[test]
+    fn creating_from_coin_refs() {
+        let coins = vec![Coin::new(69420, "uusd"), Coin::new(88888, "uluna")];
+        let list = AssetList::from_coin_refs(&coins);
+        assert_eq!(list, AssetList::from(coins));
+        assert_eq!(AssetList::from_coin_refs(&[]), AssetList::new());
+    }
+
+    #[test]
+    fn converting_to_coins() {
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uluna(), 12345u128),
+            Asset::native("ukrw", 0u128),
+            Asset::new(uusd(), 1u128),
+        ]);
+        assert_eq!(
+            list.to_coins().unwrap(),
+            vec![Coin::new(12345, "uluna"), Coin::new(69421, "uusd")]
+        );
+        assert!(AssetList::new().to_coins().unwrap().is_empty());
+
+        // duplicate entries summing past `Uint128` error rather than panic
+        let list = AssetList::from(vec![
+            Asset::native("uusd", u128::MAX),
+            Asset::native("uusd", 1u128),
+        ]);
+        assert_eq!(
+            list.to_coins(),
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Add,
+                Uint128::new(u128::MAX),
+                Uint128::new(1)
+            )))
+        );
+    }
+
+    #[test]
+    fn casting_to_coins() {
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
+        let coins: Vec<Coin> = list.clone().try_into().unwrap();
+        assert_eq!(coins, vec![Coin::new(69420, "uusd"), Coin::new(12345, "uluna")]);
+        assert_eq!(Vec::<Coin>::try_from(&list).unwrap(), coins);
+
+        assert_eq!(
+            Vec::<Coin>::try_from(mock_list()),
+            Err(AssetError::CannotCastToCoin {
+                asset: String::from("cw20:mock_token:88888"),
+            })
+        );
+    }
+
+    #[test]
+    fn iterating() {
+        let list = mock_list();
+
+        let strs: Vec<String> = list.into_iter().map(|asset| asset.to_string()).collect();
+        assert_eq!(strs, vec![
+            String::from("native:uusd:69420"),
+            String::from("cw20:mock_token:88888"),
+        ]);
+    }
+
+    #[test]
+    fn iterating_as_collection() {
+        let list = mock_list();
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+        assert!(AssetList::new().is_empty());
+
+        // borrowed iteration via the deref'd slice
+        for (index, asset) in list.iter().enumerate() {
+            assert_eq!(asset, &list[index]);
+        }
+        assert_eq!(list.iter().map(|asset| asset.amount.u128()).sum::<u128>(), 158308);
+        assert_eq!(list.first(), Some(&Asset::new(uusd(), 69420u128)));
+        assert_eq!(list.get(1..), Some(&[Asset::new(mock_token(), 88888u128)][..]));
+
+        // owned iteration
+        let assets: Vec<Asset> = list.into_iter().collect();
+        assert_eq!(assets, mock_list().to_vec());
+    }
+
+    #[test]
+    fn iterating_sorted() {
+        let list = AssetList::from(vec![
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+            Asset::native("uusd", 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::native("uluna", 67890u128),
+            Asset::native("uusd", 420u128),
+        ]);
+
+        let strs: Vec<String> = list.into_sorted_iter().map(|asset| asset.to_string()).collect();
+        assert_eq!(strs, vec![
+            String::from("native:uluna:67890"),
+            String::from("native:uusd:420"),
+            String::from("native:uusd:69420"),
+            String::from("cw20:mock_token:88888"),
+            String::from("cw20:mock_token_2:12345"),
+        ]);
+    }
+
+    #[test]
+    fn sorting() {
+        let mut list_1 = AssetList::new();
+        list_1
+            .add(&Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128))
+            .unwrap()
+            .add(&Asset::native("uusd", 69420u128))
+            .unwrap()
+            .add(&Asset::new(mock_token(), 88888u128))
+            .unwrap()
+            .add(&Asset::native("uluna", 67890u128))
+            .unwrap();
+        let mut list_2 = AssetList::from(vec![
+            Asset::native("uluna", 67890u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::native("uusd", 69420u128),
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+        ]);
+        assert_ne!(list_1.to_string(), list_2.to_string());
+
+        list_1.sort();
+        list_2.sort();
+        assert_eq!(list_1, list_2);
+        assert_eq!(
+            list_1.to_string(),
+            "native:uluna:67890,native:uusd:69420,cw20:mock_token:88888,cw20:mock_token_2:12345"
+        );
+        assert_eq!(to_vec(&list_1).unwrap(), to_vec(&list_2).unwrap());
+
+        // duplicate entries of the same asset are ordered by amount
+        let list = AssetList::from_sorted(vec![
+            Asset::native("uusd", 2u128),
+            Asset::new(mock_token(), 1u128),
+            Asset::native("uusd", 1u128),
+        ]);
+        assert_eq!(list.to_string(), "native:uusd:1,native:uusd:2,cw20:mock_token:1");
+    }
+
+    #[test]
+    fn comparing() {
+        let list_1 = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
+        let list_2 = AssetList::from(vec![
+            Asset::new(uluna(), 12345u128),
+            Asset::new(uusd(), 0u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uusd(), 69420u128),
+        ]);
+        assert_eq!(list_1, list_2);
+        assert_eq!(AssetList::new(), AssetList::from(vec![Asset::new(uusd(), 0u128)]));
+
+        // differing amounts
+        let list_3 = AssetList::from(vec![
+            Asset::new(uluna(), 12345u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uusd(), 69421u128),
+        ]);
+        assert!(list_1 != list_3);
+
+        // entries of the same asset are not merged before comparison
+        let list_4 = AssetList::from(vec![
+            Asset::new(uluna(), 12345u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uusd(), 69410u128),
+            Asset::new(uusd(), 10u128),
+        ]);
+        assert!(list_1 != list_4);
+
+        // also applies to unchecked lists
+        let unchecked_1: AssetListUnchecked = list_1.into();
+        let unchecked_2: AssetListUnchecked = list_2.into();
+        assert_eq!(unchecked_1, unchecked_2);
+    }
+
+    #[test]
+    fn counting_distinct() {
+        // lists built via `From<Vec<Asset>>` are not deduplicated
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uusd(), 12345u128),
+            Asset::new(mock_token(), 12345u128),
+            Asset::new(uluna(), 12345u128),
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+        ]);
+        assert_eq!(list.distinct_native_count(), 2);
+        assert_eq!(list.distinct_cw20_count(), 2);
+
+        assert_eq!(AssetList::new().distinct_native_count(), 0);
+        assert_eq!(AssetList::new().distinct_cw20_count(), 0);
+    }
+
+    #[test]
+    fn iterating_mut() {
+        let mut list = mock_list();
+
+        for asset in list.iter_mut() {
+            asset.amount = asset.amount.multiply_ratio(1u128, 2u128);
+        }
+        assert_eq!(
+            list,
+            AssetList::from(vec![
+                Asset::native("uusd", 34710u128),
+                Asset::new(mock_token(), 44444u128)
+            ])
+        );
+    }
+
+    #[test]
+    fn checking() {
+        let api = MockApi::default();
+
+        let checked = mock_list();
+        let unchecked: AssetListUnchecked = checked.clone().into();
+        assert_eq!(unchecked.check(&api, None).unwrap(), checked.clone());
+        assert_eq!(unchecked.check(&api, Some(&["uusd", "uluna"])).unwrap(), checked);
+        assert_eq!(
+            unchecked.check(&api, Some(&["uatom", "uosmo", "uscrt"])),
+            Err(AssetError::DenomNotWhitelisted {
+                denom: String::from("uusd"),
+                whitelist: String::from("uatom|uosmo|uscrt"),
+            }),
+        );
+    }
+
+    #[test]
+    fn checking_uppercase() {
+        let api = MockApi::default();
+
+        let checked = mock_list();
+        let unchecked = AssetListBase(vec![
+            AssetUnchecked::native("uusd", 69420u128), 
+            AssetUnchecked::cw20("MOCK_TOKEN", 88888u128),
+        ]);
+
+        assert_eq!(unchecked.check(&api, None).unwrap(), checked);
+    }
+
+    #[test]
+    fn checking_mixed_list() {
+        let api = MockApi::default();
+
+        let unchecked = AssetListBase(vec![
+            AssetUnchecked::native("uusd", 69420u128),
+            AssetUnchecked::cw20("mock_token", 88888u128),
+            AssetUnchecked::native("uluna", 12345u128),
+        ]);
+        assert_eq!(
+            unchecked.check(&api, None).unwrap(),
+            AssetList::from(vec![
+                Asset::native("uusd", 69420u128),
+                Asset::new(mock_token(), 88888u128),
+                Asset::native("uluna", 12345u128),
+            ])
+        );
+
+        // an invalid cw20 address anywhere in the list fails the whole list
+        let unchecked = AssetListBase(vec![
+            AssetUnchecked::native("uusd", 69420u128),
+            AssetUnchecked::cw20("a", 88888u128),
+        ]);
+        assert!(unchecked.check(&api, None).is_err());
+        assert!(unchecked.check_and_merge(&api, None).is_err());
+    }
+
+    #[test]
+    fn checking_and_merging() {
+        let api = MockApi::default();
+
+        let unchecked = AssetListBase(vec![
+            AssetUnchecked::native("uusd", 69420u128),
+            AssetUnchecked::cw20("MOCK_TOKEN", 88880u128),
+            AssetUnchecked::cw20("mock_token", 8u128),
+        ]);
+        assert_eq!(unchecked.check(&api, None).unwrap().len(), 3);
+        assert_eq!(unchecked.check_and_merge(&api, None).unwrap(), mock_list());
+    }
+
+    #[test]
+    fn building() {
+        let api = MockApi::default();
+
+        let mut builder = AssetListBuilder::new();
+        builder.push_native("uusd", 69420u128).push_cw20("MOCK_TOKEN", 88888u128);
+        assert_eq!(builder.finalize(&api, Some(&["uusd", "uluna"])).unwrap(), mock_list());
+
+        builder.push_native("uatom", 12345u128).push_native("uosmo", 67890u128);
+        assert_eq!(
+            builder.finalize(&api, Some(&["uusd", "uluna"])),
+            Err(vec![
+                (2, AssetError::DenomNotWhitelisted {
+                    denom: String::from("uatom"),
+                    whitelist: String::from("uusd|uluna"),
+                }),
+                (3, AssetError::DenomNotWhitelisted {
+                    denom: String::from("uosmo"),
+                    whitelist: String::from("uusd|uluna"),
+                }),
+            ]),
+        );
+    }
+
+    #[test]
+    fn splitting_native_cw20() {
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
+
+        let (natives, cw20s) = list.split_native_cw20();
+        assert_eq!(
+            natives,
+            AssetList::from(vec![Asset::new(uusd(), 69420u128), Asset::new(uluna(), 12345u128)])
+        );
+        assert_eq!(cw20s, AssetList::from(vec![Asset::new(mock_token(), 88888u128)]));
+        assert_eq!(natives.assert_all_native(), Ok(()));
+        assert_eq!(cw20s.assert_all_cw20(), Ok(()));
+
+        let (natives, cw20s) = AssetList::new().split_native_cw20();
+        assert_eq!(natives.len(), 0);
+        assert_eq!(cw20s.len(), 0);
+    }
+
+    #[test]
+    fn asserting_category() {
+        let natives = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
+        let cw20s = AssetList::from(vec![Asset::new(mock_token(), 88888u128)]);
+        let mixed = mock_list();
+
+        assert_eq!(natives.assert_all_native(), Ok(()));
+        assert_eq!(cw20s.assert_all_cw20(), Ok(()));
+        assert_eq!(AssetList::new().assert_all_native(), Ok(()));
+        assert_eq!(AssetList::new().assert_all_cw20(), Ok(()));
+
+        assert_eq!(
+            mixed.assert_all_native(),
+            Err(AssetError::UnexpectedCw20 {
+                asset: String::from("cw20:mock_token:88888"),
+            })
+        );
+        assert_eq!(
+            mixed.assert_all_cw20(),
+            Err(AssetError::UnexpectedNative {
+                asset: String::from("native:uusd:69420"),
+            })
+        );
+    }
+
+    #[test]
+    fn finding() {
+        let list = mock_list();
+
+        let asset_option = list.find(&uusd());
+        assert_eq!(asset_option, Some(&Asset::new(uusd(), 69420u128)));
+
+        let asset_option = list.find(&mock_token());
+        assert_eq!(asset_option, Some(&Asset::new(mock_token(), 88888u128)));
+
+        let asset_option = list.find(&uluna());
+        assert_eq!(asset_option, None);
+
+        assert_eq!(list.amount_of(&uusd()), Uint128::new(69420));
+        assert_eq!(list.amount_of(&uluna()), Uint128::zero());
+        assert_eq!(AssetList::new().find(&uusd()), None);
+        assert_eq!(AssetList::new().amount_of(&uusd()), Uint128::zero());
+    }
+
+    #[test]
+    fn getting() {
+        let mut list = mock_list();
+
+        assert_eq!(list.get(0), Some(&Asset::new(uusd(), 69420u128)));
+        assert_eq!(list.get(1), Some(&Asset::new(mock_token(), 88888u128)));
+        assert_eq!(list.get(2), None);
+
+        let asset = list.get_mut(&mock_token()).unwrap();
+        asset.amount = asset.amount + Uint128::new(11112);
+        assert_eq!(list.find(&mock_token()), Some(&Asset::new(mock_token(), 100000u128)));
+        assert_eq!(list.find(&uusd()), Some(&Asset::new(uusd(), 69420u128)));
+
+        assert_eq!(list.get_mut(&uluna()), None);
+        assert_eq!(AssetList::new().get_mut(&uusd()), None);
+    }
+
+    #[test]
+    fn finding_missing() {
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(uluna(), 0u128),
+        ]);
+        let universe = vec![uusd(), mock_token(), uluna()];
+
+        assert_eq!(list.missing_from(&universe), vec![mock_token(), uluna()]);
+        assert_eq!(mock_list().missing_from(&universe), vec![uluna()]);
+        assert_eq!(AssetList::new().missing_from(&universe), universe);
+        assert!(list.missing_from(&[]).is_empty());
+    }
+
+    #[test]
+    fn applying() {
+        let mut list = mock_list();
+
+        let half = Decimal::from_ratio(1u128, 2u128);
+        list.apply(|asset: &mut Asset| asset.amount = asset.amount * half);
+        assert_eq!(
+            list,
+            AssetList::from(vec![
+                Asset::native("uusd", 34710u128),
+                Asset::new(mock_token(), 44444u128)
+            ])
+        );
+    }
+
+    #[test]
+    fn mapping() {
+        let list = mock_list();
+        let haircut =
+            |a: &Asset| Asset::new(a.info.clone(), a.amount.multiply_ratio(99u128, 100u128));
+
+        let mapped = list.map(haircut);
+        assert_eq!(list, mock_list());
+        assert_eq!(
+            mapped,
+            AssetList::from(vec![
+                Asset::native("uusd", 68725u128),
+                Asset::new(mock_token(), 87999u128)
+            ])
+        );
+
+        let mut applied = mock_list();
+        applied.apply(|a| a.amount = a.amount.multiply_ratio(99u128, 100u128));
+        assert_eq!(applied, mapped);
+    }
+
+    #[test]
+    fn purging() {
+        let mut list = AssetList::from(vec![
+            Asset::new(uusd(), 0u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uluna(), 0u128),
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+        ]);
+        list.purge();
+        assert_eq!(
+            list,
+            AssetList::from(vec![
+                Asset::new(mock_token(), 88888u128),
+                Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+            ])
+        );
+    }
+
+    #[test]
+    fn retaining() {
+        let predicate = |asset: &Asset| match &asset.info {
+            AssetInfo::Native(denom) if denom == "uluna" => {
+                Err(StdError::generic_err("uluna is forbidden"))
+            }
+            AssetInfo::Native(_) => Ok(false),
+            AssetInfo::Cw20(_) => Ok(true),
+        };
+
+        let mut list = mock_list();
+        list.try_retain(predicate).unwrap();
+        assert_eq!(list, AssetList::from(vec![Asset::new(mock_token(), 88888u128)]));
+
+        let mut list = mock_list();
+        list.add(&Asset::new(uluna(), 12345u128)).unwrap();
+        let expected = list.clone();
+        assert_eq!(
+            list.try_retain(predicate),
+            Err(AssetError::Std(StdError::generic_err("uluna is forbidden")))
+        );
+        assert_eq!(list, expected);
+    }
+
+    #[test]
+    fn deduplicating() {
+        let mut list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(uusd(), 12345u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(mock_token(), 1u128),
+            Asset::new(uusd(), 67890u128),
+        ]);
+        list.dedup_first();
+        assert_eq!(list, mock_list());
+    }
+
+    #[test]
+    fn adding() {
+        let mut list = mock_list();
+
+        list.add(&Asset::new(uluna(), 12345u128)).unwrap();
+        let asset = list.find(&uluna()).unwrap();
+        assert_eq!(asset.amount, Uint128::new(12345));
+
+        list.add(&Asset::new(uusd(), 1u128)).unwrap();
+        let asset = list.find(&uusd()).unwrap();
+        assert_eq!(asset.amount, Uint128::new(69421));
+        assert_eq!(list.len(), 3);
+
+        let mut list = AssetList::new();
+        list.add(&Asset::new(uusd(), 12345u128)).unwrap();
+        list.add(&Asset::new(uusd(), 67890u128)).unwrap();
+        assert_eq!(list, AssetList::from(vec![Asset::new(uusd(), 80235u128)]));
+
+        let err = list.add(&Asset::new(uusd(), u128::MAX));
+        assert_eq!(
+            err,
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Add,
+                Uint128::new(80235),
+                Uint128::new(u128::MAX)
+            )))
+        );
+    }
+
+    #[test]
+    fn adding_many() {
+        let mut list = mock_list();
+        list.add_many(&mock_list()).unwrap();
+
+        let expected = mock_list().apply(|a| a.amount = a.amount * Uint128::new(2)).clone();
+        assert_eq!(list, expected);
+    }
+
+    #[test]
+    fn aggregating() {
+        let list = AssetList::aggregate(vec![
+            Asset::new(uusd(), 60000u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uluna(), 0u128),
+            Asset::new(uusd(), 9420u128),
+        ])
+        .unwrap();
+        assert_eq!(list, mock_list());
+
+        let err = AssetList::aggregate(vec![
+            Asset::new(uusd(), u128::MAX),
+            Asset::new(uusd(), 1u128),
+        ]);
+        assert_eq!(
+            err,
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Add,
+                Uint128::new(u128::MAX),
+                Uint128::new(1)
+            )))
+        );
+    }
+
+    #[test]
+    fn collecting_and_summing() {
+        let assets = vec![
+            Asset::new(uusd(), 60000u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uusd(), 9420u128),
+        ];
+
+        let list: AssetList = assets.clone().into_iter().collect();
+        assert_eq!(list, mock_list());
+
+        let list: AssetList = assets.into_iter().sum();
+        assert_eq!(list, mock_list());
+
+        let list: AssetList = Vec::<Asset>::new().into_iter().sum();
+        assert_eq!(list, AssetList::new());
+    }
+
+    #[test]
+    fn merging_equivalents() {
+        let mut map = EquivalenceMap::new();
+        map.insert(AssetInfo::native("ibc/usd"), uusd());
+
+        let mut list = AssetList::from(vec![
+            Asset::native("ibc/usd", 60000u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uusd(), 9420u128),
+        ]);
+        list.merge_equivalents(&map).unwrap();
+        assert_eq!(
+            list,
+            AssetList::from(vec![
+                Asset::new(uusd(), 69420u128),
+                Asset::new(mock_token(), 88888u128),
+            ])
+        );
+    }
+
+    #[test]
+    fn deducting() {
+        let mut list = mock_list();
+
+        list.deduct(&Asset::new(uusd(), 12345u128)).unwrap();
+        let asset = list.find(&uusd()).unwrap();
+        assert_eq!(asset.amount, Uint128::new(57075));
+
+        list.deduct(&Asset::new(uusd(), 57075u128)).unwrap();
+        let asset_option = list.find(&uusd());
+        assert_eq!(asset_option, None);
+
+        let err = list.deduct(&Asset::new(uusd(), 57075u128));
+        assert_eq!(err, Err(AssetError::NotFoundInList {
+            info: String::from("native:uusd"),
+        }));
+
+        list.deduct(&Asset::new(mock_token(), 12345u128)).unwrap();
+        let asset = list.find(&mock_token()).unwrap();
+        assert_eq!(asset.amount, Uint128::new(76543));
+
+        let err = list.deduct(&Asset::new(mock_token(), 99999u128));
+        assert_eq!(
+            err,
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Sub,
+                Uint128::new(76543),
+                Uint128::new(99999)
+            )))
+        );
+    }
+
+    #[test]
+    fn deducting_many() {
+        let mut list = mock_list();
+        list.deduct_many(&mock_list()).unwrap();
+        assert_eq!(list, AssetList::new());
+    }
+
+    #[test]
+    fn adding_and_deducting_immutably() {
+        let list = mock_list();
+        let other = AssetList::from(vec![
+            Asset::new(uusd(), 30580u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
+
+        let sum = list.plus(&other).unwrap();
+        assert_eq!(
+            sum,
+            AssetList::from(vec![
+                Asset::new(uusd(), 100000u128),
+                Asset::new(mock_token(), 88888u128),
+                Asset::new(uluna(), 12345u128),
+            ])
+        );
+        assert_eq!(list, mock_list());
+
+        let diff = sum.minus(&other).unwrap();
+        assert_eq!(diff, mock_list());
+        assert_eq!(sum.len(), 3);
+
+        let err = list.minus(&other);
+        assert_eq!(err, Err(AssetError::NotFoundInList {
+            info: String::from("native:uluna"),
+        }));
+        assert_eq!(list, mock_list());
+    }
+
+    #[test]
+    fn applying_ratios() {
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
+
+        let mut ratios = BTreeMap::new();
+        ratios.insert(uusd(), Decimal::permille(3));
+        ratios.insert(uluna(), Decimal::percent(50));
+
+        assert_eq!(
+            list.apply_ratios(&ratios, RoundingMode::Floor).unwrap(),
+            AssetList::from(vec![
+                Asset::new(uusd(), 208u128),
+                Asset::new(mock_token(), 88888u128),
+                Asset::new(uluna(), 6172u128),
+            ])
+        );
+        assert_eq!(
+            list.apply_ratios(&ratios, RoundingMode::Ceil).unwrap(),
+            AssetList::from(vec![
+                Asset::new(uusd(), 209u128),
+                Asset::new(mock_token(), 88888u128),
+                Asset::new(uluna(), 6173u128),
+            ])
+        );
+
+        let list = AssetList::from(vec![Asset::new(uluna(), u128::MAX)]);
+        ratios.insert(uluna(), Decimal::percent(200));
+        assert!(list.apply_ratios(&ratios, RoundingMode::Floor).is_err());
+    }
+
+    #[test]
+    fn clamping() {
+        let mut list = mock_list();
+
+        let excess = list.clamp(&AssetList::from(vec![
+            Asset::new(uusd(), 60000u128),
+            Asset::new(uluna(), 12345u128),
+        ]));
+        assert_eq!(
+            list,
+            AssetList::from(vec![
+                Asset::new(uusd(), 60000u128),
+                Asset::new(mock_token(), 88888u128),
+            ])
+        );
+        assert_eq!(excess, AssetList::from(vec![Asset::new(uusd(), 9420u128)]));
+
+        let excess = list.clamp(&AssetList::from(vec![Asset::new(mock_token(), 0u128)]));
+        assert_eq!(list, AssetList::from(vec![Asset::new(uusd(), 60000u128)]));
+        assert_eq!(excess, AssetList::from(vec![Asset::new(mock_token(), 88888u128)]));
+    }
+
+    #[test]
+    fn taking() {
+        let mut list = mock_list();
+
+        list.take(&AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(mock_token(), 8888u128),
+        ]))
+        .unwrap();
+        assert_eq!(list, AssetList::from(vec![Asset::new(mock_token(), 80000u128)]));
+
+        let err = list.take(&AssetList::from(vec![
+            Asset::new(mock_token(), 10000u128),
+            Asset::new(uusd(), 1u128),
+        ]));
+        assert_eq!(err, Err(AssetError::NotFoundInList {
+            info: String::from("native:uusd"),
+        }));
+        assert_eq!(list, AssetList::from(vec![Asset::new(mock_token(), 80000u128)]));
+
+        let err = list.take(&AssetList::from(vec![Asset::new(mock_token(), 99999u128)]));
+        assert_eq!(
+            err,
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Sub,
+                Uint128::new(80000),
+                Uint128::new(99999)
+            )))
+        );
+        assert_eq!(list, AssetList::from(vec![Asset::new(mock_token(), 80000u128)]));
+    }
+
+    #[test]
+    fn diffing_signed() {
+        let before = mock_list();
+        let after = AssetList::from(vec![
+            Asset::new(mock_token(), 99999u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
+
+        assert_eq!(
+            before.signed_diff(&after).unwrap(),
+            vec![(uusd(), -69420i128), (mock_token(), 11111i128), (uluna(), 12345i128)]
+        );
+        assert_eq!(
+            after.signed_diff(&before).unwrap(),
+            vec![(mock_token(), -11111i128), (uluna(), -12345i128), (uusd(), 69420i128)]
+        );
+        assert_eq!(before.signed_diff(&mock_list()).unwrap(), vec![]);
+
+        let huge = AssetList::from(vec![Asset::new(uusd(), u128::MAX)]);
+        assert_eq!(
+            AssetList::new().signed_diff(&huge),
+            Err(AssetError::DeltaOverflow {
+                info: String::from("native:uusd"),
+            })
+        );
+    }
+
+    #[test]
+    fn creating_messages() {
+        let list = mock_list();
+        let msgs = list.transfer_msgs("alice").unwrap();
+        assert_eq!(
+            msgs,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: String::from("alice"),
+                    amount: vec![Coin::new(69420, "uusd")]
+                }),
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: String::from("mock_token"),
+                    msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: String::from("alice"),
+                        amount: Uint128::new(88888)
+                    })
+                    .unwrap(),
+                    funds: vec![]
+                })
+            ]
+        );
+    }
+
+    #[test]
+    fn computing_info_overlap() {
+        let list = mock_list();
+        let disjoint = AssetList::from(vec![Asset::new(uluna(), 12345u128)]);
+        let partial = AssetList::from(vec![
+            Asset::new(uluna(), 12345u128),
+            Asset::new(uusd(), 1u128),
+        ]);
+
+        assert_eq!(list.info_overlap(&disjoint), Decimal::zero());
+        assert_eq!(list.info_overlap(&mock_list()), Decimal::one());
+        assert_eq!(list.info_overlap(&partial), Decimal::from_ratio(1u128, 3u128));
+        assert_eq!(partial.info_overlap(&list), Decimal::from_ratio(1u128, 3u128));
+        assert_eq!(AssetList::new().info_overlap(&AssetList::new()), Decimal::zero());
+
+        // duplicate entries count as a single asset info
+        let duplicates = AssetList::from(vec![
+            Asset::new(uusd(), 1u128),
+            Asset::new(uusd(), 2u128),
+        ]);
+        let single = AssetList::from(vec![Asset::new(uusd(), 3u128)]);
+        assert_eq!(duplicates.info_overlap(&single), Decimal::one());
+        assert_eq!(single.info_overlap(&duplicates), Decimal::one());
+        assert_eq!(duplicates.info_overlap(&partial), Decimal::from_ratio(1u128, 2u128));
+    }
+
+    #[test]
+    fn computing_weighted_price() {
+        let list = AssetList::from(vec![
+            Asset::new(uluna(), 10000u128),
+            Asset::new(uusd(), 30000u128),
+        ]);
+        let mut prices = BTreeMap::new();
+        prices.insert(uusd(), Decimal::one());
+        prices.insert(uluna(), Decimal::from_ratio(50u128, 1u128));
+        assert_eq!(list.weighted_price(&prices).unwrap(), Decimal::from_ratio(53u128, 4u128));
+
+        assert_eq!(
+            AssetList::new().weighted_price(&prices),
+            Err(AssetError::ZeroTotalAmount)
+        );
+
+        // values exceeding `Uint128` error rather than panic
+        let list = AssetList::from(vec![Asset::new(uluna(), u128::MAX)]);
+        assert!(matches!(list.weighted_price(&prices), Err(AssetError::Overflow(_))));
+
+        prices.remove(&uluna());
+        assert_eq!(
+            list.weighted_price(&prices),
+            Err(AssetError::PriceNotFound {
+                info: String::from("native:uluna"),
+            })
+        );
+    }
+
+    #[test]
+    fn computing_total_value() {
+        let list = AssetList::from(vec![
+            Asset::new(uluna(), 10001u128),
+            Asset::new(uusd(), 30000u128),
+        ]);
+        let mut prices = HashMap::new();
+        prices.insert(uusd(), Decimal::one());
+        prices.insert(uluna(), Decimal::from_ratio(1u128, 3u128));
+        // floor(10001 / 3) + 30000
+        assert_eq!(list.total_value(&prices).unwrap(), Uint128::new(33333));
+
+        prices.remove(&uluna());
+        assert_eq!(
+            list.total_value(&prices),
+            Err(AssetError::PriceNotFound {
+                info: String::from("native:uluna"),
+            })
+        );
 
-    pub fn uluna() -> AssetInfo {
-        AssetInfo::native("uluna")
-    }
+        assert_eq!(AssetList::new().total_value(&prices).unwrap(), Uint128::zero());
 
-    pub fn uusd() -> AssetInfo {
-        AssetInfo::native("uusd")
+        // intermediate products exceeding `Uint128` are fine as long as the result fits
+        let list = AssetList::from(vec![Asset::new(uusd(), u128::MAX)]);
+        let mut prices = HashMap::new();
+        prices.insert(uusd(), Decimal::percent(50));
+        assert_eq!(list.total_value(&prices).unwrap(), Uint128::new(u128::MAX / 2));
     }
 
-    pub fn mock_token() -> AssetInfo {
-        AssetInfo::cw20(Addr::unchecked("mock_token"))
-    }
+    #[test]
+    fn covering_from_funds() {
+        let mut list = mock_list();
+        list.add(&Asset::new(uluna(), 12345u128)).unwrap();
 
-    pub fn mock_list() -> AssetList {
-        AssetList::from(vec![
-            Asset::native("uusd", 69420u128), 
+        let funds = vec![Coin::new(12345, "uluna"), Coin::new(69420, "uusd"), Coin::new(1, "ukrw")];
+        assert_eq!(list.cover_from_funds(&funds), Ok(()));
+
+        let funds = vec![Coin::new(100000, "uusd")];
+        assert_eq!(
+            list.cover_from_funds(&funds),
+            Err(AssetError::InsufficientFunds {
+                uncovered: String::from("native:uluna:12345"),
+            })
+        );
+
+        let funds = vec![Coin::new(12344, "uluna"), Coin::new(69419, "uusd")];
+        assert_eq!(
+            list.cover_from_funds(&funds),
+            Err(AssetError::InsufficientFunds {
+                uncovered: String::from("native:uluna:12345,native:uusd:69420"),
+            })
+        );
+
+        // duplicate obligations are summed up, and funds split between them
+        let list = AssetList::from(vec![
+            Asset::native("uusd", 60u128),
             Asset::new(mock_token(), 88888u128),
-        ])
+            Asset::native("uusd", 60u128),
+        ]);
+        assert_eq!(
+            list.cover_from_funds(&[Coin::new(100, "uusd")]),
+            Err(AssetError::InsufficientFunds {
+                uncovered: String::from("native:uusd:120"),
+            })
+        );
+        assert_eq!(list.cover_from_funds(&[Coin::new(70, "uusd"), Coin::new(50, "uusd")]), Ok(()));
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::super::asset::{Asset, AssetUnchecked};
-    use super::test_helpers::{mock_list, mock_token, uluna, uusd};
-    use super::*;
-    use cosmwasm_std::testing::MockApi;
-    use cosmwasm_std::{
-        to_binary, BankMsg, Coin, CosmosMsg, Decimal, OverflowError, OverflowOperation, Uint128,
-        WasmMsg,
-    };
-    use cw20::Cw20ExecuteMsg;
 
     #[test]
-    fn from_string() {
-        let s = "";
-        assert_eq!(AssetListUnchecked::from_str(s).unwrap(), AssetListBase::<String>(vec![]));
+    fn checking_funds_and_splitting() {
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
 
-        let s = "native:uusd:69420,cw20:mock_token";
+        let funds = vec![Coin::new(12345, "uluna"), Coin::new(69420, "uusd")];
         assert_eq!(
-            AssetListUnchecked::from_str(s),
-            Err(StdError::generic_err("invalid asset format `cw20:mock_token`; must be in format `native:{denom}:{amount}` or `cw20:{contract_addr}:{amount}`")),
+            list.check_funds_and_split(&funds),
+            Ok(AssetList::from(vec![Asset::new(mock_token(), 88888u128)]))
         );
 
-        let s = "native:uusd:69420,cw721:galactic_punk:1";
+        let funds = vec![Coin::new(69420, "uusd"), Coin::new(1, "uluna")];
         assert_eq!(
-            AssetListUnchecked::from_str(s),
-            Err(StdError::generic_err("invalid asset type `cw721`; must be `native` or `cw20`")),
+            list.check_funds_and_split(&funds),
+            Err(AssetError::SentFundsMismatch {
+                discrepancies: String::from("uluna: expected 12345, received 1"),
+            })
         );
 
-        let s = "native:uusd:69420,cw20:mock_token:ngmi";
+        // coins of the same denom are summed up, the same as in `assert_sent`
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 1u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uusd(), 2u128),
+            Asset::cw20(Addr::unchecked("mock_token_2"), 0u128),
+        ]);
+        let funds = vec![Coin::new(3, "uusd")];
+        assert_eq!(list.assert_sent(&funds), Ok(()));
         assert_eq!(
-            AssetListUnchecked::from_str(s),
-            Err(StdError::generic_err("invalid asset amount `ngmi`; must be a 128-bit unsigned integer")),
+            list.check_funds_and_split(&funds),
+            Ok(AssetList::from(vec![Asset::new(mock_token(), 88888u128)]))
         );
 
-        let s = "native:uusd:69420,cw20:mock_token:88888";
-        assert_eq!(AssetListUnchecked::from_str(s).unwrap(), AssetListUnchecked::from(mock_list()));
+        let funds = vec![Coin::new(69420, "uusd"), Coin::new(12345, "uluna"), Coin::new(1, "ukrw")];
+        assert!(list.check_funds_and_split(&funds).is_err());
     }
 
     #[test]
-    fn to_string() {
-        let list = mock_list();
-        assert_eq!(list.to_string(), String::from("native:uusd:69420,cw20:mock_token:88888"));
-    }
+    fn asserting_sent() {
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
 
-    #[test]
-    fn indexing() {
-        let list = mock_list();
-        let vec = list.to_vec();
-        assert_eq!(list[0], vec[0]);
-        assert_eq!(list[1], vec[1]);
+        // exact match; order, zero amounts and cw20 tokens in the list do not matter
+        let funds = vec![Coin::new(12345, "uluna"), Coin::new(0, "ukrw"), Coin::new(69420, "uusd")];
+        assert_eq!(list.assert_sent(&funds), Ok(()));
+        let funds =
+            vec![Coin::new(69420, "uusd"), Coin::new(12340, "uluna"), Coin::new(5, "uluna")];
+        assert_eq!(list.assert_sent(&funds), Ok(()));
+
+        // missing denom
+        let funds = vec![Coin::new(69420, "uusd")];
+        assert_eq!(
+            list.assert_sent(&funds),
+            Err(AssetError::SentFundsMismatch {
+                discrepancies: String::from("uluna: expected 12345, received 0"),
+            })
+        );
+
+        // wrong amounts, and an unexpected denom
+        let funds = vec![Coin::new(69421, "uusd"), Coin::new(12344, "uluna"), Coin::new(1, "ukrw")];
+        assert_eq!(
+            list.assert_sent(&funds),
+            Err(AssetError::SentFundsMismatch {
+                discrepancies: String::from("ukrw: expected 0, received 1; uluna: expected 12345, received 12344; uusd: expected 69420, received 69421"),
+            })
+        );
+
+        // received funds summing past `Uint128` error rather than panic
+        let funds = vec![Coin::new(u128::MAX, "uusd"), Coin::new(1, "uusd")];
+        assert!(list.assert_sent(&funds).is_err());
     }
 
     #[test]
-    fn iterating() {
-        let list = mock_list();
-
-        let strs: Vec<String> = list.into_iter().map(|asset| asset.to_string()).collect();
-        assert_eq!(strs, vec![
-            String::from("native:uusd:69420"),
-            String::from("cw20:mock_token:88888"),
+    fn mapping_cw20_initial_balances() {
+        let list = AssetList::from(vec![
+            Asset::new(uusd(), 69420u128),
+            Asset::new(uluna(), 12345u128),
         ]);
+        let balances = list.to_cw20_initial_balances_mapping(|asset| match &asset.info {
+            AssetInfo::Native(denom) => Addr::unchecked(format!("{}_holder", denom)),
+            AssetInfo::Cw20(contract_addr) => contract_addr.clone(),
+        });
+        assert_eq!(
+            balances,
+            vec![
+                Cw20Coin {
+                    address: String::from("uusd_holder"),
+                    amount: Uint128::new(69420),
+                },
+                Cw20Coin {
+                    address: String::from("uluna_holder"),
+                    amount: Uint128::new(12345),
+                },
+            ]
+        );
     }
 
     #[test]
-    fn checking() {
-        let api = MockApi::default();
+    fn creating_send_messages() {
+        let bin_msg = to_binary(&"mock_command").unwrap();
 
-        let checked = mock_list();
-        let unchecked: AssetListUnchecked = checked.clone().into();
-        assert_eq!(unchecked.check(&api, None).unwrap(), checked.clone());
-        assert_eq!(unchecked.check(&api, Some(&["uusd", "uluna"])).unwrap(), checked);
+        let list = AssetList::from(vec![
+            Asset::new(mock_token(), 88888u128),
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+        ]);
+        let msgs = list.send_msgs("mock_contract", bin_msg.clone()).unwrap();
+        assert_eq!(msgs.len(), 2);
         assert_eq!(
-            unchecked.check(&api, Some(&["uatom", "uosmo", "uscrt"])),
-            Err(StdError::generic_err("invalid denom uusd; must be uatom|uosmo|uscrt")),
+            msgs[1],
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: String::from("mock_token_2"),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: String::from("mock_contract"),
+                    amount: Uint128::new(12345),
+                    msg: bin_msg.clone(),
+                })
+                .unwrap(),
+                funds: vec![]
+            })
+        );
+
+        assert_eq!(
+            mock_list().send_msgs("mock_contract", bin_msg),
+            Err(AssetError::not_supported_for_native("send"))
         );
     }
 
     #[test]
-    fn checking_uppercase() {
-        let api = MockApi::default();
-
-        let checked = mock_list();
-        let unchecked = AssetListBase(vec![
-            AssetUnchecked::native("uusd", 69420u128), 
-            AssetUnchecked::cw20("MOCK_TOKEN", 88888u128),
+    fn creating_pull_messages() {
+        let list = AssetList::from(vec![
+            Asset::new(mock_token(), 88888u128),
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
         ]);
+        let msgs = list.pull_from_msgs("bob", "charlie").unwrap();
+        assert_eq!(
+            msgs,
+            vec![
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: String::from("mock_token"),
+                    msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                        owner: String::from("bob"),
+                        recipient: String::from("charlie"),
+                        amount: Uint128::new(88888)
+                    })
+                    .unwrap(),
+                    funds: vec![]
+                }),
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: String::from("mock_token_2"),
+                    msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                        owner: String::from("bob"),
+                        recipient: String::from("charlie"),
+                        amount: Uint128::new(12345)
+                    })
+                    .unwrap(),
+                    funds: vec![]
+                }),
+            ]
+        );
 
-        assert_eq!(unchecked.check(&api, None).unwrap(), checked);
+        let mut list = mock_list();
+        list.add(&Asset::new(uluna(), 12345u128)).unwrap();
+        assert_eq!(
+            list.pull_from_msgs("bob", "charlie"),
+            Err(AssetError::CannotPullNative {
+                assets: String::from("native:uusd:69420,native:uluna:12345"),
+            })
+        );
     }
 
     #[test]
-    fn finding() {
-        let list = mock_list();
-
-        let asset_option = list.find(&uusd());
-        assert_eq!(asset_option, Some(&Asset::new(uusd(), 69420u128)));
+    fn creating_minimal_messages() {
+        let list = AssetList::from(vec![
+            Asset::native("uusd", 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::native("ukrw", 12345u128),
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+            Asset::native("uluna", 67890u128),
+        ]);
+        let msgs = list.transfer_msgs("alice").unwrap();
 
-        let asset_option = list.find(&mock_token());
-        assert_eq!(asset_option, Some(&Asset::new(mock_token(), 88888u128)));
+        let bank_msgs: Vec<&CosmosMsg> =
+            msgs.iter().filter(|msg| matches!(msg, CosmosMsg::Bank(_))).collect();
+        let wasm_msgs: Vec<&CosmosMsg> =
+            msgs.iter().filter(|msg| matches!(msg, CosmosMsg::Wasm(_))).collect();
+        assert_eq!(msgs.len(), 3);
+        assert_eq!(wasm_msgs.len(), 2);
+        assert_eq!(
+            bank_msgs,
+            vec![&CosmosMsg::Bank(BankMsg::Send {
+                to_address: String::from("alice"),
+                amount: vec![
+                    Coin::new(12345, "ukrw"),
+                    Coin::new(67890, "uluna"),
+                    Coin::new(69420, "uusd")
+                ]
+            })]
+        );
     }
 
     #[test]
-    fn applying() {
-        let mut list = mock_list();
+    fn creating_minimal_messages_merging_coins() {
+        let list = AssetList::from(vec![
+            Asset::native("uusd", 1u128),
+            Asset::native("ukrw", 0u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::native("uusd", 2u128),
+        ]);
+        assert_eq!(
+            list.transfer_msgs("alice").unwrap(),
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: String::from("alice"),
+                    amount: vec![Coin::new(3, "uusd")]
+                }),
+                Asset::new(mock_token(), 88888u128).transfer_msg("alice").unwrap(),
+            ]
+        );
 
-        let half = Decimal::from_ratio(1u128, 2u128);
-        list.apply(|asset: &mut Asset| asset.amount = asset.amount * half);
+        // no bank message if all native coins are of zero amount
+        let list = AssetList::from(vec![
+            Asset::native("uusd", 0u128),
+            Asset::new(mock_token(), 88888u128),
+        ]);
         assert_eq!(
-            list,
-            AssetList::from(vec![
-                Asset::native("uusd", 34710u128),
-                Asset::new(mock_token(), 44444u128)
-            ])
+            list.transfer_msgs("alice").unwrap(),
+            vec![Asset::new(mock_token(), 88888u128).transfer_msg("alice").unwrap()]
         );
     }
 
     #[test]
-    fn adding() {
-        let mut list = mock_list();
+    fn appending_messages() {
+        let list = mock_list();
+        let existing = Asset::new(uluna(), 12345u128).transfer_msg("bob").unwrap();
 
-        list.add(&Asset::new(uluna(), 12345u128)).unwrap();
-        let asset = list.find(&uluna()).unwrap();
-        assert_eq!(asset.amount, Uint128::new(12345));
+        let mut msgs = vec![existing.clone()];
+        list.append_transfer_msgs("alice", &mut msgs).unwrap();
 
-        list.add(&Asset::new(uusd(), 1u128)).unwrap();
-        let asset = list.find(&uusd()).unwrap();
-        assert_eq!(asset.amount, Uint128::new(69421));
+        let mut expected = vec![existing];
+        expected.extend(list.transfer_msgs("alice").unwrap());
+        assert_eq!(msgs, expected);
     }
 
     #[test]
-    fn adding_many() {
-        let mut list = mock_list();
-        list.add_many(&mock_list()).unwrap();
+    fn creating_refund_messages() {
+        let list = mock_list();
+        assert_eq!(list.refund_msgs("alice").unwrap(), list.transfer_msgs("alice").unwrap());
 
-        let expected = mock_list().apply(|a| a.amount = a.amount * Uint128::new(2)).clone();
-        assert_eq!(list, expected);
+        let list = AssetList::from(vec![
+            Asset::new(mock_token(), 88888u128),
+            Asset::native("uusd", 69420u128),
+            Asset::native("uluna", 12345u128),
+        ]);
+        assert_eq!(
+            list.refund_msgs("alice").unwrap(),
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: String::from("alice"),
+                    amount: vec![Coin::new(12345, "uluna"), Coin::new(69420, "uusd")]
+                }),
+                Asset::new(mock_token(), 88888u128).transfer_msg("alice").unwrap(),
+            ]
+        );
     }
 
     #[test]
-    fn deducting() {
-        let mut list = mock_list();
-
-        list.deduct(&Asset::new(uusd(), 12345u128)).unwrap();
-        let asset = list.find(&uusd()).unwrap();
-        assert_eq!(asset.amount, Uint128::new(57075));
-
-        list.deduct(&Asset::new(uusd(), 57075u128)).unwrap();
-        let asset_option = list.find(&uusd());
-        assert_eq!(asset_option, None);
-
-        let err = list.deduct(&Asset::new(uusd(), 57075u128));
-        assert_eq!(err, Err(StdError::generic_err("not found in asset list: native:uusd")));
-
-        list.deduct(&Asset::new(mock_token(), 12345u128)).unwrap();
-        let asset = list.find(&mock_token()).unwrap();
-        assert_eq!(asset.amount, Uint128::new(76543));
+    fn creating_transfer_response() {
+        let list = mock_list();
+        let res = list.transfer_response("alice").unwrap();
 
-        let err = list.deduct(&Asset::new(mock_token(), 99999u128));
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(res.messages[0].msg, list.refund_msgs("alice").unwrap()[0]);
+        assert_eq!(res.messages[1].msg, list.refund_msgs("alice").unwrap()[1]);
         assert_eq!(
-            err,
-            Err(StdError::overflow(OverflowError::new(
-                OverflowOperation::Sub,
-                Uint128::new(76543),
-                Uint128::new(99999)
-            )))
+            res.attributes,
+            vec![attr("assets_sent", "native:uusd:69420,cw20:mock_token:88888")]
         );
     }
 
     #[test]
-    fn deducting_many() {
-        let mut list = mock_list();
-        list.deduct_many(&mock_list()).unwrap();
-        assert_eq!(list, AssetList::new());
-    }
+    fn creating_messages_deterministically() {
+        let list1 = AssetList::from(vec![
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+            Asset::native("uusd", 69420u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::native("uluna", 67890u128),
+        ]);
+        let list2 = AssetList::from(vec![
+            Asset::native("uluna", 67890u128),
+            Asset::new(mock_token(), 88888u128),
+            Asset::native("uusd", 69420u128),
+            Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128),
+        ]);
 
-    #[test]
-    fn creating_messages() {
-        let list = mock_list();
-        let msgs = list.transfer_msgs("alice").unwrap();
+        let msgs1 = list1.transfer_msgs("alice").unwrap();
+        let msgs2 = list2.transfer_msgs("alice").unwrap();
+        assert_eq!(msgs1, msgs2);
         assert_eq!(
-            msgs,
+            msgs1,
             vec![
                 CosmosMsg::Bank(BankMsg::Send {
                     to_address: String::from("alice"),
-                    amount: vec![Coin::new(69420, "uusd")]
+                    amount: vec![Coin::new(67890, "uluna"), Coin::new(69420, "uusd")]
                 }),
-                CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: String::from("mock_token"),
-                    msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                        recipient: String::from("alice"),
-                        amount: Uint128::new(88888)
-                    })
+                Asset::new(mock_token(), 88888u128).transfer_msg("alice").unwrap(),
+                Asset::cw20(Addr::unchecked("mock_token_2"), 12345u128)
+                    .transfer_msg("alice")
                     .unwrap(),
-                    funds: vec![]
-                })
             ]
         );
     }
@@ -710,7 +3221,67 @@ mod tests_legacy {
 
         assert_eq!(
             list.try_into_legacy(), 
-            Err(StdError::generic_err("failed to map AssetList to legacy: native:uusd:69420,cw20:mock_token:88888,native:ukrw:12345"))
+            Err(AssetError::LegacyCastFailed {
+                list: String::from("native:uusd:69420,cw20:mock_token:88888,native:ukrw:12345"),
+            })
+        );
+    }
+}
+
+#[cfg(all(test, feature = "stargate"))]
+mod tests_stargate {
+    use super::test_helpers::{mock_list, uluna};
+    use super::*;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+
+    #[test]
+    fn casting_proto_coins() {
+        let list = AssetList::from(vec![
+            Asset::native("uusd", 69420u128),
+            Asset::new(uluna(), 12345u128),
+        ]);
+        assert_eq!(
+            list.to_proto_coins().unwrap(),
+            vec![
+                ProtoCoin {
+                    denom: String::from("uluna"),
+                    amount: String::from("12345"),
+                },
+                ProtoCoin {
+                    denom: String::from("uusd"),
+                    amount: String::from("69420"),
+                },
+            ]
+        );
+
+        assert_eq!(
+            mock_list().to_proto_coins(),
+            Err(AssetError::CannotCastToCoin {
+                asset: String::from("cw20:mock_token:88888"),
+            })
         );
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests_std {
+    use super::test_helpers::mock_list;
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn converting_value() {
+        let list = mock_list();
+        let value = json!([
+            { "info": { "native": "uusd" }, "amount": "69420" },
+            { "info": { "cw20": "mock_token" }, "amount": "88888" },
+        ]);
+
+        assert_eq!(list.to_value().unwrap(), value);
+        assert_eq!(AssetList::from_value(value).unwrap(), list);
+        assert_eq!(AssetList::from_value(list.to_value().unwrap()).unwrap(), list);
+
+        let err = AssetList::from_value(json!({ "native": "uusd" }));
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,93 @@
+//! A serde adapter that represents an **asset info** as a single "denom string", for interop with
+//! external schemas that only understand plain denoms:
+//!
+//! - Native SDK coins are represented by their denom, e.g. `uusd`
+//! - CW20 tokens are represented by their contract address prefixed with `cw20:`, e.g.
+//!   `cw20:token_addr`
+//!
+//! Use it with serde's `with` attribute:
+//!
+//! ```rust
+//! use serde::{Serialize, Deserialize};
+//! use schemars::JsonSchema;
+//!
+//! use cw_asset::AssetInfoUnchecked;
+//!
+//! #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+//! pub struct Config {
+//!     #[serde(with = "cw_asset::denom_string")]
+//!     #[schemars(with = "String")]
+//!     pub fee_asset: AssetInfoUnchecked,
+//! }
+//! ```
+//!
+//! NOTE: When deserializing, any string starting with `cw20:` is interpreted as a CW20 token, so
+//! native denoms of this form cannot be represented.
+use serde::{Deserialize, Deserializer, Serializer};
+
+use super::asset_info::AssetInfoUnchecked;
+
+const CW20_PREFIX: &str = "cw20:";
+
+/// Serialize an **asset info** instance as a denom string
+pub fn serialize<S: Serializer>(
+    info: &AssetInfoUnchecked,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match info {
+        AssetInfoUnchecked::Cw20(contract_addr) => {
+            serializer.serialize_str(&format!("{}{}", CW20_PREFIX, contract_addr))
+        }
+        AssetInfoUnchecked::Native(denom) => serializer.serialize_str(denom),
+    }
+}
+
+/// Deserialize an **asset info** instance from a denom string
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<AssetInfoUnchecked, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    Ok(match s.strip_prefix(CW20_PREFIX) {
+        Some(contract_addr) => AssetInfoUnchecked::cw20(contract_addr),
+        None => AssetInfoUnchecked::Native(s),
+    })
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{from_slice, to_vec};
+    use serde::Serialize;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct MockConfig {
+        #[serde(with = "super")]
+        asset: AssetInfoUnchecked,
+    }
+
+    #[test]
+    fn serializing() {
+        let config = MockConfig {
+            asset: AssetInfoUnchecked::native("uusd"),
+        };
+        let json = br#"{"asset":"uusd"}"#;
+        assert_eq!(to_vec(&config).unwrap(), json.to_vec());
+        assert_eq!(from_slice::<MockConfig>(json).unwrap(), config);
+
+        let config = MockConfig {
+            asset: AssetInfoUnchecked::cw20("mock_token"),
+        };
+        let json = br#"{"asset":"cw20:mock_token"}"#;
+        assert_eq!(to_vec(&config).unwrap(), json.to_vec());
+        assert_eq!(from_slice::<MockConfig>(json).unwrap(), config);
+
+        let config = MockConfig {
+            asset: AssetInfoUnchecked::native("ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97"),
+        };
+        assert_eq!(from_slice::<MockConfig>(&to_vec(&config).unwrap()).unwrap(), config);
+    }
+}
@@ -0,0 +1,381 @@
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+use thiserror::Error;
+
+/// Failure modes of parsing, validating, or operating on assets
+///
+/// Fallible methods of this crate return this error, so failure modes can be matched on directly.
+/// Errors raised by `cosmwasm_std` itself, e.g. when serializing a message or querying the chain,
+/// are carried in the `Std` variant.
+///
+/// It converts into a [`StdError`], so the methods can still be used with `?` in contracts that
+/// return [`cosmwasm_std::StdResult`]. `Std` converts back into the error it carries, `Overflow`
+/// into a [`StdError::Overflow`], and every other variant into a [`StdError::GenericErr`]
+/// carrying the variant's message.
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use cw_asset::{AssetError, AssetInfoUnchecked};
+///
+/// match AssetInfoUnchecked::from_str("cw721:galactic_punk") {
+///     Err(AssetError::InvalidAssetType { ty }) => {
+///         // should be `cw721`
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Error, Debug, PartialEq)]
+pub enum AssetError {
+    #[error("invalid asset info format `{received}`; must be in format `native:{{denom}}` or `cw20:{{contract_addr}}`")]
+    InvalidAssetInfoFormat {
+        received: String,
+    },
+
+    #[error("invalid asset format `{received}`; must be in format `native:{{denom}}:{{amount}}` or `cw20:{{contract_addr}}:{{amount}}`")]
+    InvalidAssetFormat {
+        received: String,
+    },
+
+    #[error("invalid asset type `{ty}`; must be `native` or `cw20`")]
+    InvalidAssetType {
+        ty: String,
+    },
+
+    #[error("invalid asset amount `{amount}`; must be a 128-bit unsigned integer")]
+    InvalidAssetAmount {
+        amount: String,
+    },
+
+    #[error("invalid denom `{denom}`; must be 3-128 characters long, start with a letter, and contain only letters, digits, or `/:._-`")]
+    InvalidDenom {
+        denom: String,
+    },
+
+    #[error("invalid denom {denom}; must be {whitelist}")]
+    DenomNotWhitelisted {
+        denom: String,
+        whitelist: String,
+    },
+
+    #[error("denom {denom} is not allowed")]
+    DenomBlocked {
+        denom: String,
+    },
+
+    #[error("amount of {info} must be non-zero")]
+    ZeroAmount {
+        info: String,
+    },
+
+    #[error("invalid storage namespace `{namespace}`")]
+    InvalidStorageNamespace {
+        namespace: String,
+    },
+
+    #[error("invalid asset info bytes {bytes:?}")]
+    InvalidAssetInfoBytes {
+        bytes: Vec<u8>,
+    },
+
+    #[error("invalid asset info storage key {key:?}")]
+    InvalidStorageKey {
+        key: Vec<u8>,
+    },
+
+    #[error("native coins do not have `{method}` method")]
+    MethodNotSupportedForNative {
+        method: String,
+    },
+
+    #[error("cannot cast asset {asset} into cosmwasm_std::Coin")]
+    CannotCastToCoin {
+        asset: String,
+    },
+
+    #[error("cannot cast native balance of {count} coins into a single asset")]
+    CannotCastNativeBalance {
+        count: usize,
+    },
+
+    #[error("cannot {operation} assets of different types: {left} vs {right}")]
+    IncompatibleOperands {
+        operation: String,
+        left: String,
+        right: String,
+    },
+
+    #[error("cannot multiply {asset} by ratio with zero denominator")]
+    ZeroDenominator {
+        asset: String,
+    },
+
+    #[error("cannot split {asset}: recipient amounts add up to {total}")]
+    SplitMismatch {
+        asset: String,
+        total: Uint128,
+    },
+
+    #[error("cannot deduct coin {coin} from asset {asset}: denom mismatch")]
+    DeductDenomMismatch {
+        coin: String,
+        asset: String,
+    },
+
+    #[error("cannot deduct coin {coin} from asset {asset}: not a native coin")]
+    DeductFromCw20 {
+        coin: String,
+        asset: String,
+    },
+
+    #[error("cannot compare {asset} against {reference}: asset mismatch")]
+    AssetMismatch {
+        asset: String,
+        reference: String,
+    },
+
+    #[error("cannot compare {asset} against zero reference amount")]
+    ZeroReferenceAmount {
+        asset: String,
+    },
+
+    #[error("expecting native coins only, found {asset}")]
+    UnexpectedCw20 {
+        asset: String,
+    },
+
+    #[error("expecting cw20 tokens only, found {asset}")]
+    UnexpectedNative {
+        asset: String,
+    },
+
+    #[error("not found in asset list: {info}")]
+    NotFoundInList {
+        info: String,
+    },
+
+    #[error("native coins cannot be pulled and must be sent as funds: {assets}")]
+    CannotPullNative {
+        assets: String,
+    },
+
+    #[error("failed to map AssetList to legacy: {list}")]
+    LegacyCastFailed {
+        list: String,
+    },
+
+    #[error("price not found: {info}")]
+    PriceNotFound {
+        info: String,
+    },
+
+    #[error("cannot compute weighted price of zero total amount")]
+    ZeroTotalAmount,
+
+    #[error("insufficient funds to cover: {uncovered}")]
+    InsufficientFunds {
+        uncovered: String,
+    },
+
+    #[error("sent funds mismatch: {discrepancies}")]
+    SentFundsMismatch {
+        discrepancies: String,
+    },
+
+    #[error("delta of {info} overflows i128")]
+    DeltaOverflow {
+        info: String,
+    },
+
+    #[error("denom {denom} is not a token factory denom")]
+    NotTokenFactoryDenom {
+        denom: String,
+    },
+
+    #[error("cw20 tokens cannot be minted or burned via token factory")]
+    TokenFactoryCw20,
+
+    #[error("denom metadata not found for {denom}")]
+    DenomMetadataNotFound {
+        denom: String,
+    },
+
+    #[error("querier contract error: {msg}")]
+    QuerierContractError {
+        msg: String,
+    },
+
+    #[error("querier system error: {msg}")]
+    QuerierSystemError {
+        msg: String,
+    },
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    Std(#[from] StdError),
+}
+
+impl AssetError {
+    /// Create an error for a method that native coins do not support, e.g. `send`
+    pub fn not_supported_for_native<A: Into<String>>(method: A) -> Self {
+        AssetError::MethodNotSupportedForNative {
+            method: method.into(),
+        }
+    }
+}
+
+impl From<AssetError> for StdError {
+    fn from(err: AssetError) -> Self {
+        match err {
+            AssetError::Overflow(err) => StdError::overflow(err),
+            AssetError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Asset, AssetInfo, AssetInfoUnchecked, AssetList, AssetUnchecked};
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::{Addr, Coin, Decimal, OverflowOperation, Uint128};
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    #[test]
+    fn converting_to_std_error() {
+        assert_eq!(
+            StdError::from(AssetError::not_supported_for_native("send")),
+            StdError::generic_err("native coins do not have `send` method")
+        );
+
+        let overflow =
+            || OverflowError::new(OverflowOperation::Add, Uint128::new(u128::MAX), Uint128::new(1));
+        assert_eq!(StdError::from(AssetError::from(overflow())), StdError::overflow(overflow()));
+
+        let not_found = || StdError::not_found("cw20::state::TokenInfo");
+        assert_eq!(StdError::from(AssetError::from(not_found())), not_found());
+    }
+
+    #[test]
+    fn producing_variants() {
+        assert_eq!(
+            AssetInfoUnchecked::from_str("native:uusd:12345"),
+            Err(AssetError::InvalidAssetInfoFormat {
+                received: String::from("native:uusd:12345"),
+            })
+        );
+        assert_eq!(
+            AssetInfoUnchecked::from_str("cw721:galactic_punk"),
+            Err(AssetError::InvalidAssetType {
+                ty: String::from("cw721"),
+            })
+        );
+        assert_eq!(
+            AssetUnchecked::from_str("native:uusd"),
+            Err(AssetError::InvalidAssetFormat {
+                received: String::from("native:uusd"),
+            })
+        );
+        assert_eq!(
+            AssetUnchecked::from_str("native:uusd:ngmi"),
+            Err(AssetError::InvalidAssetAmount {
+                amount: String::from("ngmi"),
+            })
+        );
+        let api = MockApi::default();
+        assert_eq!(
+            AssetInfoUnchecked::native("uatom").check(&api, Some(&["uusd", "uluna"])),
+            Err(AssetError::DenomNotWhitelisted {
+                denom: String::from("uatom"),
+                whitelist: String::from("uusd|uluna"),
+            })
+        );
+
+        assert_eq!(
+            AssetInfoUnchecked::native("u$d").check(&api, None),
+            Err(AssetError::InvalidDenom {
+                denom: String::from("u$d"),
+            })
+        );
+
+        let coin = Asset::native("uusd", 12345u128);
+        let expected = |method: &str| Err(AssetError::not_supported_for_native(method));
+        assert_eq!(coin.send_msg("alice", Default::default()), expected("send"));
+        assert_eq!(coin.transfer_from_msg("alice", "bob"), expected("transfer_from"));
+        assert_eq!(coin.increase_allowance_msg("bob", None), expected("increase_allowance"));
+        assert_eq!(coin.decrease_allowance_msg("bob", None), expected("decrease_allowance"));
+        assert_eq!(coin.burn_msg(), expected("burn"));
+
+        let token = Asset::cw20(Addr::unchecked("mock_token"), u128::MAX);
+        assert_eq!(
+            token.checked_add(&Asset::cw20(Addr::unchecked("mock_token"), 1u128)),
+            Err(AssetError::Overflow(OverflowError::new(
+                OverflowOperation::Add,
+                Uint128::new(u128::MAX),
+                Uint128::new(1)
+            )))
+        );
+    }
+
+    #[test]
+    fn producing_list_variants() {
+        let uusd = Asset::native("uusd", 100u128);
+        assert_eq!(
+            uusd.percent_of(&Asset::native("uluna", 100u128)),
+            Err(AssetError::AssetMismatch {
+                asset: String::from("native:uusd:100"),
+                reference: String::from("native:uluna:100"),
+            })
+        );
+        assert_eq!(
+            uusd.percent_of(&Asset::native("uusd", 0u128)),
+            Err(AssetError::ZeroReferenceAmount {
+                asset: String::from("native:uusd:100"),
+            })
+        );
+
+        let list = AssetList::from(vec![uusd.clone()]);
+        assert_eq!(
+            list.weighted_price(&BTreeMap::new()),
+            Err(AssetError::PriceNotFound {
+                info: String::from("native:uusd"),
+            })
+        );
+        let mut prices = BTreeMap::new();
+        prices.insert(AssetInfo::native("uusd"), Decimal::one());
+        assert_eq!(
+            AssetList::new().weighted_price(&prices),
+            Err(AssetError::ZeroTotalAmount)
+        );
+
+        assert_eq!(
+            list.cover_from_funds(&[Coin::new(99, "uusd")]),
+            Err(AssetError::InsufficientFunds {
+                uncovered: String::from("native:uusd:100"),
+            })
+        );
+        assert_eq!(
+            list.assert_sent(&[Coin::new(99, "uusd")]),
+            Err(AssetError::SentFundsMismatch {
+                discrepancies: String::from("uusd: expected 100, received 99"),
+            })
+        );
+        assert_eq!(
+            list.check_funds_and_split(&[]),
+            Err(AssetError::SentFundsMismatch {
+                discrepancies: String::from("uusd: expected 100, received 0"),
+            })
+        );
+
+        let whale = AssetList::from(vec![Asset::native("uusd", u128::MAX)]);
+        assert_eq!(
+            AssetList::new().signed_diff(&whale),
+            Err(AssetError::DeltaOverflow {
+                info: String::from("native:uusd"),
+            })
+        );
+    }
+}
@@ -107,10 +107,14 @@
 mod asset;
 mod asset_info;
 mod asset_list;
+pub mod denom_string;
+mod error;
+pub mod string_serde;
 
 pub use asset::*;
 pub use asset_info::*;
 pub use asset_list::*;
+pub use error::*;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod testing;
@@ -0,0 +1,103 @@
+//! A serde adapter that represents an **asset info** as a flat string in the same format as its
+//! `Display` and `FromStr` implementations, i.e. `native:{denom}` or `cw20:{contract_addr}`, rather
+//! than the default tagged enum representation, e.g. `{"native":"uusd"}`
+//!
+//! Use it with serde's `with` attribute:
+//!
+//! ```rust
+//! use serde::{Serialize, Deserialize};
+//! use schemars::JsonSchema;
+//!
+//! use cw_asset::AssetInfoUnchecked;
+//!
+//! #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+//! pub struct Config {
+//!     #[serde(with = "cw_asset::string_serde")]
+//!     #[schemars(with = "String")]
+//!     pub fee_asset: AssetInfoUnchecked,
+//! }
+//! ```
+//!
+//! NOTE: Unlike [`denom_string`](crate::denom_string), both variants carry an explicit prefix, so
+//! the two can be told apart unambiguously. Deserializing rejects strings that do not parse.
+use std::str::FromStr;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use super::asset_info::AssetInfoUnchecked;
+
+/// Serialize an **asset info** instance as a string, e.g. `native:uusd`
+pub fn serialize<S: Serializer>(
+    info: &AssetInfoUnchecked,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&info.to_string())
+}
+
+/// Deserialize an **asset info** instance from a string, e.g. `native:uusd`
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<AssetInfoUnchecked, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    AssetInfoUnchecked::from_str(&s).map_err(D::Error::custom)
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{from_slice, to_vec};
+    use serde::Serialize;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct MockConfig {
+        #[serde(with = "super")]
+        asset: AssetInfoUnchecked,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct MockConfigDefault {
+        asset: AssetInfoUnchecked,
+    }
+
+    #[test]
+    fn serializing() {
+        let config = MockConfig {
+            asset: AssetInfoUnchecked::native("uusd"),
+        };
+        let json = br#"{"asset":"native:uusd"}"#;
+        assert_eq!(to_vec(&config).unwrap(), json.to_vec());
+        assert_eq!(from_slice::<MockConfig>(json).unwrap(), config);
+
+        let config = MockConfig {
+            asset: AssetInfoUnchecked::cw20("mock_token"),
+        };
+        let json = br#"{"asset":"cw20:mock_token"}"#;
+        assert_eq!(to_vec(&config).unwrap(), json.to_vec());
+        assert_eq!(from_slice::<MockConfig>(json).unwrap(), config);
+
+        assert!(from_slice::<MockConfig>(br#"{"asset":"uusd"}"#).is_err());
+        assert!(from_slice::<MockConfig>(br#"{"asset":"erc20:mock_token"}"#).is_err());
+    }
+
+    #[test]
+    fn serializing_default_unchanged() {
+        let config = MockConfigDefault {
+            asset: AssetInfoUnchecked::native("uusd"),
+        };
+        let json = br#"{"asset":{"native":"uusd"}}"#;
+        assert_eq!(to_vec(&config).unwrap(), json.to_vec());
+        assert_eq!(from_slice::<MockConfigDefault>(json).unwrap(), config);
+
+        let config = MockConfigDefault {
+            asset: AssetInfoUnchecked::cw20("mock_token"),
+        };
+        let json = br#"{"asset":{"cw20":"mock_token"}}"#;
+        assert_eq!(to_vec(&config).unwrap(), json.to_vec());
+        assert_eq!(from_slice::<MockConfigDefault>(json).unwrap(), config);
+    }
+}
@@ -1,15 +1,23 @@
+use std::collections::{HashMap, HashSet};
+
 use cosmwasm_std::testing::MockQuerier;
+#[cfg(feature = "stargate")]
+use cosmwasm_std::Binary;
 use cosmwasm_std::{
-    from_binary, from_slice, Addr, Coin, Empty, Querier, QuerierResult, QueryRequest, StdResult,
-    SystemError, WasmQuery,
+    from_binary, from_slice, to_binary, Addr, BankQuery, Coin, ContractResult, Empty, Querier,
+    QuerierResult, QueryRequest, StdResult, SystemError, WasmQuery,
 };
-use cw20::Cw20QueryMsg;
+use cw20::{Cw20QueryMsg, TokenInfoResponse};
 
 use super::cw20_querier::Cw20Querier;
+use crate::{AssetInfoUnchecked, BalanceRouterQueryMsg};
 
 pub struct CustomMockQuerier {
     base: MockQuerier<Empty>,
     cw20_querier: Cw20Querier,
+    balance_routers: HashSet<Addr>,
+    bank_query_errors: HashMap<String, String>,
+    denom_symbols: HashMap<String, String>,
 }
 
 impl Default for CustomMockQuerier {
@@ -17,6 +25,9 @@ impl Default for CustomMockQuerier {
         CustomMockQuerier {
             base: MockQuerier::<Empty>::new(&[]),
             cw20_querier: Cw20Querier::default(),
+            balance_routers: HashSet::new(),
+            bank_query_errors: HashMap::new(),
+            denom_symbols: HashMap::new(),
         }
     }
 }
@@ -46,6 +57,13 @@ impl CustomMockQuerier {
             }) => {
                 let contract_addr = Addr::unchecked(contract_addr);
 
+                if self.balance_routers.contains(&contract_addr) {
+                    let parse_router_query: StdResult<BalanceRouterQueryMsg> = from_binary(msg);
+                    if let Ok(router_query) = parse_router_query {
+                        return self.handle_router_query(router_query);
+                    }
+                }
+
                 let parse_cw20_query: StdResult<Cw20QueryMsg> = from_binary(msg);
                 if let Ok(cw20_query) = parse_cw20_query {
                     return self.cw20_querier.handle_query(&contract_addr, cw20_query);
@@ -54,15 +72,164 @@ impl CustomMockQuerier {
                 panic!("[mock]: unsupported wasm query {:?}", msg);
             }
 
+            QueryRequest::Bank(BankQuery::Balance {
+                denom,
+                ..
+            }) if self.bank_query_errors.contains_key(denom) => {
+                Ok(ContractResult::Err(self.bank_query_errors[denom].clone())).into()
+            }
+
+            #[cfg(feature = "stargate")]
+            QueryRequest::Stargate {
+                path,
+                data,
+            } if path == "/cosmos.bank.v1beta1.Query/DenomMetadata" => {
+                self.handle_denom_metadata_query(data)
+            }
+
             _ => self.base.handle_query(request),
         }
     }
 
+    #[cfg(feature = "stargate")]
+    fn handle_denom_metadata_query(&self, data: &Binary) -> QuerierResult {
+        use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+            Metadata, QueryDenomMetadataRequest, QueryDenomMetadataResponse,
+        };
+        use prost::Message;
+
+        let request = QueryDenomMetadataRequest::decode(data.as_slice()).unwrap();
+        match self.denom_symbols.get(&request.denom) {
+            Some(symbol) => {
+                let response = QueryDenomMetadataResponse {
+                    metadata: Some(Metadata {
+                        base: request.denom,
+                        symbol: symbol.clone(),
+                        ..Default::default()
+                    }),
+                };
+                Ok(ContractResult::Ok(Binary::from(response.encode_to_vec()))).into()
+            }
+            None => Ok(ContractResult::Err(format!(
+                "client metadata for denom {}: denom metadata not found",
+                request.denom
+            )))
+            .into(),
+        }
+    }
+
+    fn handle_router_query(&self, query: BalanceRouterQueryMsg) -> QuerierResult {
+        let BalanceRouterQueryMsg::Balance {
+            asset_info,
+            address,
+        } = query;
+
+        match asset_info {
+            AssetInfoUnchecked::Cw20(contract_addr) => self.cw20_querier.handle_query(
+                &Addr::unchecked(contract_addr),
+                Cw20QueryMsg::Balance {
+                    address,
+                },
+            ),
+            AssetInfoUnchecked::Native(denom) => {
+                let request = QueryRequest::Bank(BankQuery::Balance {
+                    address,
+                    denom,
+                });
+                // the bank module responds with a `cosmwasm_std::BalanceResponse`, whereas the
+                // router is expected to respond with a `cw20::BalanceResponse`
+                let response: cosmwasm_std::BalanceResponse =
+                    from_binary(&self.base.handle_query(&request).into_result().unwrap().unwrap())
+                        .unwrap();
+                Ok(to_binary(&cw20::BalanceResponse {
+                    balance: response.amount.amount,
+                })
+                .into())
+                .into()
+            }
+        }
+    }
+
     pub fn set_base_balances(&mut self, address: &str, balances: &[Coin]) {
         self.base.update_balance(address, balances.to_vec());
     }
 
+    /// Set the native balances of several addresses at once, e.g. for testing queries that
+    /// aggregate over all balances; each address' balances are replaced entirely
+    pub fn set_all_balances(&mut self, balances: &[(&str, &[Coin])]) {
+        for (address, coins) in balances {
+            self.set_base_balances(address, coins);
+        }
+    }
+
     pub fn set_cw20_balance(&mut self, contract: &str, user: &str, balance: u128) {
         self.cw20_querier.set_balance(contract, user, balance);
     }
+
+    pub fn set_balance_router(&mut self, router: &str) {
+        self.balance_routers.insert(Addr::unchecked(router));
+    }
+
+    pub fn set_bank_query_error(&mut self, denom: &str, error: &str) {
+        self.bank_query_errors.insert(denom.to_string(), error.to_string());
+    }
+
+    pub fn set_cw20_token_info(&mut self, contract: &str, token_info: TokenInfoResponse) {
+        self.cw20_querier.set_token_info(contract, token_info);
+    }
+
+    pub fn set_denom_symbol(&mut self, denom: &str, symbol: &str) {
+        self.denom_symbols.insert(denom.to_string(), symbol.to_string());
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{QuerierWrapper, Uint128};
+
+    #[test]
+    fn querying_all_balances() {
+        let mut querier = CustomMockQuerier::default();
+        querier.set_all_balances(&[
+            ("alice", &[Coin::new(69420, "uusd"), Coin::new(12345, "uluna")]),
+            ("bob", &[Coin::new(88888, "uusd")]),
+        ]);
+
+        let wrapper = QuerierWrapper::new(&querier);
+        assert_eq!(
+            wrapper.query_all_balances("alice").unwrap(),
+            vec![Coin::new(69420, "uusd"), Coin::new(12345, "uluna")]
+        );
+        assert_eq!(wrapper.query_all_balances("bob").unwrap(), vec![Coin::new(88888, "uusd")]);
+        assert!(wrapper.query_all_balances("charlie").unwrap().is_empty());
+        assert_eq!(wrapper.query_balance("alice", "uluna").unwrap(), Coin::new(12345, "uluna"));
+    }
+
+    #[test]
+    fn querying_cw20_token_info() {
+        let token_info = TokenInfoResponse {
+            name: String::from("Mock Token"),
+            symbol: String::from("MOCK"),
+            decimals: 6,
+            total_supply: Uint128::new(1000000),
+        };
+
+        let mut querier = CustomMockQuerier::default();
+        querier.set_cw20_token_info("mock_token", token_info.clone());
+
+        let wrapper = QuerierWrapper::new(&querier);
+        let res: TokenInfoResponse =
+            wrapper.query_wasm_smart("mock_token", &Cw20QueryMsg::TokenInfo {}).unwrap();
+        assert_eq!(res, token_info);
+        assert_eq!(res.total_supply, Uint128::new(1000000));
+
+        let res: StdResult<TokenInfoResponse> =
+            wrapper.query_wasm_smart("another_token", &Cw20QueryMsg::TokenInfo {});
+        assert!(res.is_err());
+    }
 }
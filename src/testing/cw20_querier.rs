@@ -1,10 +1,11 @@
 use cosmwasm_std::{to_binary, Addr, QuerierResult, SystemError, Uint128};
-use cw20::{BalanceResponse, Cw20QueryMsg};
+use cw20::{BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 use std::collections::HashMap;
 
 #[derive(Default)]
 pub struct Cw20Querier {
     balances: HashMap<Addr, HashMap<Addr, Uint128>>,
+    token_infos: HashMap<Addr, TokenInfoResponse>,
 }
 
 impl Cw20Querier {
@@ -45,6 +46,15 @@ impl Cw20Querier {
                 .into()
             }
 
+            Cw20QueryMsg::TokenInfo {} => match self.token_infos.get(contract_addr) {
+                Some(token_info) => Ok(to_binary(token_info).into()).into(),
+                None => Err(SystemError::InvalidRequest {
+                    error: format!("[mock]: token info not set for token {:?}", contract_addr),
+                    request: Default::default(),
+                })
+                .into(),
+            },
+
             query => Err(SystemError::InvalidRequest {
                 error: format!("[mock]: unsupported cw20 query {:?}", query),
                 request: Default::default(),
@@ -60,4 +70,8 @@ impl Cw20Querier {
         let contract_balances = self.balances.entry(contract_addr).or_insert_with(HashMap::new);
         contract_balances.insert(user_addr, Uint128::new(balance));
     }
+
+    pub fn set_token_info(&mut self, contract: &str, token_info: TokenInfoResponse) {
+        self.token_infos.insert(Addr::unchecked(contract), token_info);
+    }
 }
@@ -0,0 +1,186 @@
+use cosmwasm_std::{
+    from_slice, to_binary, to_vec, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult, Storage,
+};
+use cw20::Cw20ReceiveMsg;
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{Asset, AssetInfo, AssetList, AssetListUnchecked};
+
+const DEPOSITS_KEY: &[u8] = b"deposits";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositExecuteMsg {
+    /// Deposit native coins, which must be attached as funds, and CW20 tokens, which are drawn from
+    /// the sender and therefore require an allowance
+    Deposit {
+        assets: AssetListUnchecked,
+    },
+    /// Deposit a CW20 token by sending it to the contract
+    Receive(Cw20ReceiveMsg),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositQueryMsg {
+    /// Return all deposits recorded so far, in chronological order
+    Deposits {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DepositRecord {
+    pub depositor: Addr,
+    pub assets: AssetList,
+}
+
+fn load_deposits(storage: &dyn Storage) -> StdResult<Vec<DepositRecord>> {
+    match storage.get(DEPOSITS_KEY) {
+        Some(bytes) => from_slice(&bytes),
+        None => Ok(vec![]),
+    }
+}
+
+fn record_deposit(storage: &mut dyn Storage, record: DepositRecord) -> StdResult<()> {
+    let mut deposits = load_deposits(storage)?;
+    deposits.push(record);
+    storage.set(DEPOSITS_KEY, &to_vec(&deposits)?);
+    Ok(())
+}
+
+fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: DepositExecuteMsg,
+) -> StdResult<Response> {
+    let (depositor, assets, msgs) = match msg {
+        DepositExecuteMsg::Deposit {
+            assets,
+        } => {
+            let assets = assets.check(deps.api, None)?;
+            assets.cover_from_funds(&info.funds)?;
+            let mut cw20s = assets.clone();
+            cw20s.try_retain(|asset| Ok(matches!(asset.info, AssetInfo::Cw20(_))))?;
+            let msgs = cw20s.pull_from_msgs(&info.sender, &env.contract.address)?;
+            (info.sender, assets, msgs)
+        }
+        DepositExecuteMsg::Receive(cw20_msg) => {
+            let depositor = deps.api.addr_validate(&cw20_msg.sender)?;
+            let assets = AssetList::from(vec![Asset::cw20(info.sender, cw20_msg.amount)]);
+            (depositor, assets, vec![])
+        }
+    };
+
+    record_deposit(deps.storage, DepositRecord {
+        depositor,
+        assets: assets.clone(),
+    })?;
+
+    Ok(Response::new().add_messages(msgs).add_attribute("assets_deposited", assets.to_string()))
+}
+
+fn query(deps: Deps, _env: Env, msg: DepositQueryMsg) -> StdResult<Binary> {
+    match msg {
+        DepositQueryMsg::Deposits {} => to_binary(&load_deposits(deps.storage)?),
+    }
+}
+
+/// A mock contract that accepts deposits of assets and records them, to be used as a realistic
+/// receiver of assets in `cw-multi-test` tests
+pub fn mock_deposit_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
+/// Store and instantiate the mock deposit contract, returning its address
+pub fn deploy_deposit_contract(app: &mut App, owner: &Addr) -> Addr {
+    let code_id = app.store_code(mock_deposit_contract());
+    app.instantiate_contract(code_id, owner.clone(), &Empty {}, &[], "mock_deposit_contract", None)
+        .unwrap()
+}
+
+/// Query all deposits recorded by the mock deposit contract
+pub fn query_deposits(app: &App, contract_addr: &Addr) -> Vec<DepositRecord> {
+    app.wrap().query_wasm_smart(contract_addr, &DepositQueryMsg::Deposits {}).unwrap()
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Coin;
+    use cw_multi_test::AppBuilder;
+
+    #[test]
+    fn depositing_native_coins() {
+        let owner = Addr::unchecked("owner");
+        let alice = Addr::unchecked("alice");
+
+        let mut app: App = AppBuilder::new().build();
+        app.init_bank_balance(&alice, vec![Coin::new(69420, "uusd"), Coin::new(12345, "uluna")])
+            .unwrap();
+
+        let contract_addr = deploy_deposit_contract(&mut app, &owner);
+        assert_eq!(query_deposits(&app, &contract_addr), vec![]);
+
+        let assets = AssetList::from(vec![
+            Asset::native("uusd", 69420u128),
+            Asset::native("uluna", 12345u128),
+        ]);
+        app.execute_contract(
+            alice.clone(),
+            contract_addr.clone(),
+            &DepositExecuteMsg::Deposit {
+                assets: assets.clone().into(),
+            },
+            &[Coin::new(69420, "uusd"), Coin::new(12345, "uluna")],
+        )
+        .unwrap();
+
+        assert_eq!(
+            query_deposits(&app, &contract_addr),
+            vec![DepositRecord {
+                depositor: alice.clone(),
+                assets,
+            }]
+        );
+
+        // depositing without attaching the funds is rejected
+        let res = app.execute_contract(
+            alice.clone(),
+            contract_addr.clone(),
+            &DepositExecuteMsg::Deposit {
+                assets: AssetList::from(vec![Asset::native("uusd", 1u128)]).into(),
+            },
+            &[],
+        );
+        assert!(res.is_err());
+        assert_eq!(query_deposits(&app, &contract_addr).len(), 1);
+
+        // duplicate entries cannot claim the same funds twice
+        app.init_bank_balance(&alice, vec![Coin::new(100, "uusd")]).unwrap();
+        let res = app.execute_contract(
+            alice,
+            contract_addr.clone(),
+            &DepositExecuteMsg::Deposit {
+                assets: AssetList::from(vec![
+                    Asset::native("uusd", 60u128),
+                    Asset::native("uusd", 60u128),
+                ])
+                .into(),
+            },
+            &[Coin::new(100, "uusd")],
+        );
+        assert!(res.is_err());
+        assert_eq!(query_deposits(&app, &contract_addr).len(), 1);
+    }
+}
@@ -1,6 +1,17 @@
 mod custom_mock_querier;
 mod cw20_querier;
+#[cfg(feature = "multi-test")]
+mod deposit_contract;
 mod helpers;
+#[cfg(feature = "multi-test")]
+mod multi_test;
 
 pub use custom_mock_querier::CustomMockQuerier;
+#[cfg(feature = "multi-test")]
+pub use deposit_contract::{
+    deploy_deposit_contract, mock_deposit_contract, query_deposits, DepositExecuteMsg,
+    DepositQueryMsg, DepositRecord,
+};
 pub use helpers::mock_dependencies;
+#[cfg(feature = "multi-test")]
+pub use multi_test::mock_cw20_contract;
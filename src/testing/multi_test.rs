@@ -0,0 +1,161 @@
+use cosmwasm_std::{Addr, Coin, Empty};
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+
+use crate::{Asset, AssetInfo};
+
+/// The `cw20-base` contract, wrapped to be stored in a `cw-multi-test` app
+pub fn mock_cw20_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    ))
+}
+
+impl AssetInfo {
+    /// Store and instantiate a `cw20-base` token with the given minter and initial balances,
+    /// returning an **asset info** instance of the resulting token
+    ///
+    /// Panics if the instantiation fails; only meant to be used in tests.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::{Addr, Uint128};
+    /// use cw20::Cw20Coin;
+    /// use cw_asset::AssetInfo;
+    /// use cw_multi_test::{App, AppBuilder};
+    ///
+    /// let mut app: App = AppBuilder::new().build();
+    /// let token = AssetInfo::instantiate_cw20(
+    ///     &mut app,
+    ///     &Addr::unchecked("minter"),
+    ///     "MOCK",
+    ///     vec![Cw20Coin {
+    ///         address: String::from("alice"),
+    ///         amount: Uint128::new(12345),
+    ///     }],
+    /// );
+    /// ```
+    pub fn instantiate_cw20(
+        app: &mut App,
+        minter: &Addr,
+        symbol: &str,
+        initial_balances: Vec<Cw20Coin>,
+    ) -> AssetInfo {
+        let code_id = app.store_code(mock_cw20_contract());
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                minter.clone(),
+                &cw20_base::msg::InstantiateMsg {
+                    name: format!("Mock token {}", symbol),
+                    symbol: symbol.to_string(),
+                    decimals: 6,
+                    initial_balances,
+                    mint: Some(MinterResponse {
+                        minter: minter.to_string(),
+                        cap: None,
+                    }),
+                    marketing: None,
+                },
+                &[],
+                symbol,
+                None,
+            )
+            .unwrap();
+        AssetInfo::Cw20(contract_addr)
+    }
+}
+
+impl Asset {
+    /// Credit the asset to the recipient's balance
+    ///
+    /// For native coins, the recipient's bank balance is increased by the amount. For CW20 tokens,
+    /// the amount is minted to the recipient on behalf of the token's minter, so the token must
+    /// have one, e.g. be created by [`AssetInfo::instantiate_cw20`].
+    ///
+    /// Panics if funding fails; only meant to be used in tests.
+    ///
+    /// ```rust
+    /// use cosmwasm_std::Addr;
+    /// use cw_asset::Asset;
+    /// use cw_multi_test::{App, AppBuilder};
+    ///
+    /// let mut app: App = AppBuilder::new().build();
+    /// Asset::native("uusd", 12345u128).fund(&mut app, &Addr::unchecked("alice"));
+    /// ```
+    pub fn fund(&self, app: &mut App, recipient: &Addr) {
+        match &self.info {
+            AssetInfo::Native(denom) => {
+                let mut balances = app.wrap().query_all_balances(recipient).unwrap();
+                match balances.iter_mut().find(|coin| coin.denom == *denom) {
+                    Some(coin) => coin.amount += self.amount,
+                    None => balances.push(Coin {
+                        denom: denom.clone(),
+                        amount: self.amount,
+                    }),
+                }
+                app.init_bank_balance(recipient, balances).unwrap();
+            }
+            AssetInfo::Cw20(contract_addr) => {
+                let minter: Option<MinterResponse> =
+                    app.wrap().query_wasm_smart(contract_addr, &Cw20QueryMsg::Minter {}).unwrap();
+                let minter = minter.expect("cw20 token has no minter");
+                app.execute_contract(
+                    Addr::unchecked(minter.minter),
+                    contract_addr.clone(),
+                    &Cw20ExecuteMsg::Mint {
+                        recipient: recipient.to_string(),
+                        amount: self.amount,
+                    },
+                    &[],
+                )
+                .unwrap();
+            }
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Uint128;
+    use cw_multi_test::AppBuilder;
+
+    #[test]
+    fn funding_assets() {
+        let minter = Addr::unchecked("minter");
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+
+        let mut app: App = AppBuilder::new().build();
+        let token = AssetInfo::instantiate_cw20(&mut app, &minter, "MOCK", vec![Cw20Coin {
+            address: alice.to_string(),
+            amount: Uint128::new(100),
+        }]);
+        let uusd = AssetInfo::native("uusd");
+
+        let balance = |app: &App, info: &AssetInfo, addr: &Addr| {
+            info.query_balance(&app.wrap(), addr).unwrap().u128()
+        };
+        assert_eq!(balance(&app, &token, &alice), 100);
+        assert_eq!(balance(&app, &token, &bob), 0);
+
+        Asset::new(token.clone(), 23u128).fund(&mut app, &alice);
+        Asset::new(token.clone(), 45u128).fund(&mut app, &bob);
+        assert_eq!(balance(&app, &token, &alice), 123);
+        assert_eq!(balance(&app, &token, &bob), 45);
+
+        // funding native coins adds to, rather than replaces, existing balances
+        Asset::new(uusd.clone(), 69420u128).fund(&mut app, &alice);
+        Asset::native("uluna", 12345u128).fund(&mut app, &alice);
+        Asset::new(uusd.clone(), 1u128).fund(&mut app, &alice);
+        assert_eq!(balance(&app, &uusd, &alice), 69421);
+        assert_eq!(balance(&app, &AssetInfo::native("uluna"), &alice), 12345);
+        assert_eq!(balance(&app, &uusd, &bob), 0);
+    }
+}